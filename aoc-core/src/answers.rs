@@ -0,0 +1,224 @@
+use crate::{BoxedError, Part};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AnswersError {
+    #[error("Unable to read answers from {0}, {1}")]
+    Read(PathBuf, std::io::Error),
+    #[error("Unable to write answers to {0}, {1}")]
+    Write(PathBuf, std::io::Error),
+    #[error("Line {0} of answers is not `part1 = ...` or `part2 = ...`, found {1:?}")]
+    Line(usize, String),
+}
+
+/// Known answers for one input, stored next to it as `<input>.answers`:
+///
+/// ```text
+/// # comments and blank lines are ignored
+/// part1 = 72017
+/// part2 = 212520
+/// ```
+///
+/// Multi-line answers are written with `\n` escapes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    parts: [Option<String>; 2],
+}
+
+impl Answers {
+    /// Path of the answers file for an input file.
+    pub fn path_for(input: &Path) -> PathBuf {
+        input.with_extension("answers")
+    }
+
+    /// Reads an answers file, `None` if there isn't one.
+    pub fn load(path: &Path) -> Result<Option<Self>, AnswersError> {
+        match std::fs::read_to_string(path) {
+            Ok(s) => Ok(Some(s.parse()?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(AnswersError::Read(path.to_path_buf(), e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        std::fs::write(path, self.to_string())
+            .map_err(|e| AnswersError::Write(path.to_path_buf(), e))
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        self.parts[part as usize - 1].as_deref()
+    }
+
+    pub fn set(&mut self, part: Part, answer: impl Into<String>) {
+        self.parts[part as usize - 1] = Some(answer.into());
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = AnswersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        for (idx, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let bad_line = || AnswersError::Line(idx + 1, line.to_string());
+            let (key, value) = line.split_once('=').ok_or_else(bad_line)?;
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                _ => return Err(bad_line()),
+            };
+            answers.set(part, unescape(value.trim()).ok_or_else(bad_line)?);
+        }
+        Ok(answers)
+    }
+}
+
+impl std::fmt::Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for part in Part::ALL {
+            if let Some(answer) = self.get(part) {
+                writeln!(f, "part{} = {}", part, escape(answer))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(s: &str) -> Option<String> {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                'n' => out.push('\n'),
+                '\\' => out.push('\\'),
+                _ => return None,
+            },
+            c => out.push(c),
+        }
+    }
+    Some(out)
+}
+
+/// Outcome of checking one part's answer against the known answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Mismatch {
+        expected: String,
+        actual: String,
+    },
+    /// Solved, but there's no known answer to compare with.
+    Unknown(String),
+    Failed(String),
+}
+
+impl Verdict {
+    pub fn check(expected: Option<&str>, result: Result<String, BoxedError>) -> Self {
+        match (expected, result) {
+            (_, Err(e)) => Verdict::Failed(e.to_string()),
+            (None, Ok(actual)) => Verdict::Unknown(actual),
+            (Some(expected), Ok(actual)) if expected == actual => Verdict::Pass,
+            (Some(expected), Ok(actual)) => Verdict::Mismatch {
+                expected: expected.to_string(),
+                actual,
+            },
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Verdict::Pass | Verdict::Unknown(_))
+    }
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Mismatch { expected, actual } => write!(
+                f,
+                "MISMATCH, expected {}, got {}",
+                escape(expected),
+                escape(actual)
+            ),
+            Verdict::Unknown(actual) => write!(f, "no known answer, got {}", escape(actual)),
+            Verdict::Failed(e) => write!(f, "FAILED, {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_from_str_works() {
+        let s = "\
+# day 10
+part1 = 13140
+
+part2 = ##..\\n#..#
+";
+        let answers: Answers = s.parse().unwrap();
+        assert_eq!(Some("13140"), answers.get(Part::One));
+        assert_eq!(Some("##..\n#..#"), answers.get(Part::Two));
+
+        let answers: Answers = "part2 = 4".parse().unwrap();
+        assert_eq!(None, answers.get(Part::One));
+
+        assert!(matches!(
+            "part1 = 1\npart3 = 2".parse::<Answers>(),
+            Err(AnswersError::Line(2, _))
+        ));
+        assert!(matches!(
+            "part1 = a\\tb".parse::<Answers>(),
+            Err(AnswersError::Line(1, _))
+        ));
+    }
+
+    #[test]
+    fn answers_display_round_trips() {
+        let mut answers = Answers::default();
+        answers.set(Part::One, "CMZ");
+        answers.set(Part::Two, "#.\\\n.#");
+        let s = answers.to_string();
+        assert_eq!("part1 = CMZ\npart2 = #.\\\\\\n.#\n", s);
+        assert_eq!(answers, s.parse().unwrap());
+    }
+
+    #[test]
+    fn answers_path_for_works() {
+        assert_eq!(
+            PathBuf::from("day1/src/input.answers"),
+            Answers::path_for(Path::new("day1/src/input.txt"))
+        );
+    }
+
+    #[test]
+    fn verdict_check_works() {
+        assert_eq!(Verdict::Pass, Verdict::check(Some("4"), Ok("4".into())));
+        assert_eq!(
+            Verdict::Mismatch {
+                expected: "4".into(),
+                actual: "5".into()
+            },
+            Verdict::check(Some("4"), Ok("5".into()))
+        );
+        assert_eq!(
+            Verdict::Unknown("5".into()),
+            Verdict::check(None, Ok("5".into()))
+        );
+        let failed = Verdict::check(Some("4"), Err("bad input".into()));
+        assert_eq!(Verdict::Failed("bad input".into()), failed);
+        assert!(!failed.is_ok());
+    }
+}
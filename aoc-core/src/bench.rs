@@ -0,0 +1,230 @@
+use crate::{BoxedError, Solution};
+use std::collections::BTreeMap;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// Most samples taken of one stage, however fast it is.
+const MAX_SAMPLES: usize = 1000;
+/// Changes against a baseline smaller than this, in percent, are noise.
+pub const NOISE_PERCENT: f64 = 5.0;
+
+#[derive(Error, Debug)]
+pub enum BaselineError {
+    #[error("Unable to read baseline from {0}, {1}")]
+    Read(PathBuf, std::io::Error),
+    #[error("Unable to write baseline to {0}, {1}")]
+    Write(PathBuf, std::io::Error),
+    #[error("Line {0} of baseline is not `DAY STAGE NANOS`, found {1:?}")]
+    Line(usize, String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Parse, Stage::Part1, Stage::Part2];
+}
+
+impl std::fmt::Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part1 => write!(f, "part1"),
+            Stage::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+impl std::str::FromStr for Stage {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Stage::Parse),
+            "part1" => Ok(Stage::Part1),
+            "part2" => Ok(Stage::Part2),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Median time taken by each stage of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn get(&self, stage: Stage) -> Duration {
+        match stage {
+            Stage::Parse => self.parse,
+            Stage::Part1 => self.part1,
+            Stage::Part2 => self.part2,
+        }
+    }
+}
+
+/// Times parsing `input` and solving each part from the parsed input,
+/// sampling each stage until `budget` is used up.
+pub fn measure<S: Solution>(input: &str, budget: Duration) -> Result<Timings, BoxedError> {
+    let parsed = S::parse(input)?;
+    S::part1(&parsed)?;
+    S::part2(&parsed)?;
+    Ok(Timings {
+        parse: median_time(budget, || {
+            let _ = black_box(S::parse(black_box(input)));
+        }),
+        part1: median_time(budget, || {
+            let _ = black_box(S::part1(black_box(&parsed)));
+        }),
+        part2: median_time(budget, || {
+            let _ = black_box(S::part2(black_box(&parsed)));
+        }),
+    })
+}
+
+fn median_time<F: FnMut()>(budget: Duration, mut f: F) -> Duration {
+    let mut samples = Vec::new();
+    let start = Instant::now();
+    while samples.is_empty() || (start.elapsed() < budget && samples.len() < MAX_SAMPLES) {
+        let t = Instant::now();
+        f();
+        samples.push(t.elapsed());
+    }
+    samples.sort();
+    samples[samples.len() / 2]
+}
+
+/// Saved timings to compare later runs against, one `DAY STAGE NANOS` per line.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    times: BTreeMap<(u8, Stage), Duration>,
+}
+
+impl Baseline {
+    /// Reads a baseline, `None` if it hasn't been saved.
+    pub fn load(path: &Path) -> Result<Option<Self>, BaselineError> {
+        match std::fs::read_to_string(path) {
+            Ok(s) => Ok(Some(s.parse()?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(BaselineError::Read(path.to_path_buf(), e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), BaselineError> {
+        let write = || -> std::io::Result<()> {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            std::fs::write(path, self.to_string())
+        };
+        write().map_err(|e| BaselineError::Write(path.to_path_buf(), e))
+    }
+
+    pub fn get(&self, day: u8, stage: Stage) -> Option<Duration> {
+        self.times.get(&(day, stage)).copied()
+    }
+
+    pub fn insert(&mut self, day: u8, timings: &Timings) {
+        for stage in Stage::ALL {
+            self.times.insert((day, stage), timings.get(stage));
+        }
+    }
+}
+
+impl std::str::FromStr for Baseline {
+    type Err = BaselineError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut baseline = Baseline::default();
+        for (idx, line) in s.lines().enumerate() {
+            let bad_line = || BaselineError::Line(idx + 1, line.to_string());
+            let [day, stage, nanos] = line.split_whitespace().collect::<Vec<_>>()[..] else {
+                return Err(bad_line());
+            };
+            let day = day.parse().map_err(|_| bad_line())?;
+            let stage = stage.parse().map_err(|_| bad_line())?;
+            let nanos = nanos.parse().map_err(|_| bad_line())?;
+            baseline
+                .times
+                .insert((day, stage), Duration::from_nanos(nanos));
+        }
+        Ok(baseline)
+    }
+}
+
+impl std::fmt::Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for ((day, stage), d) in &self.times {
+            writeln!(f, "{} {} {}", day, stage, d.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+/// Change from `before` to `after` in percent, positive when slower.
+pub fn change_percent(before: Duration, after: Duration) -> f64 {
+    (after.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0
+}
+
+/// Formats a duration in the largest unit that keeps it above 1.
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos() as f64;
+    match nanos {
+        n if n >= 1e9 => format!("{:.3} s", n / 1e9),
+        n if n >= 1e6 => format!("{:.3} ms", n / 1e6),
+        n if n >= 1e3 => format!("{:.3} µs", n / 1e3),
+        n => format!("{} ns", n),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn baseline_round_trips() {
+        let mut baseline = Baseline::default();
+        let timings = Timings {
+            parse: Duration::from_nanos(1500),
+            part1: Duration::from_micros(20),
+            part2: Duration::from_millis(3),
+        };
+        baseline.insert(8, &timings);
+        let s = baseline.to_string();
+        assert_eq!("8 parse 1500\n8 part1 20000\n8 part2 3000000\n", s);
+
+        let loaded: Baseline = s.parse().unwrap();
+        assert_eq!(baseline, loaded);
+        assert_eq!(Some(Duration::from_micros(20)), loaded.get(8, Stage::Part1));
+        assert_eq!(None, loaded.get(9, Stage::Part1));
+
+        assert!(matches!(
+            "8 parse 1500\n8 part3 1".parse::<Baseline>(),
+            Err(BaselineError::Line(2, _))
+        ));
+    }
+
+    #[test]
+    fn change_percent_works() {
+        let ms = Duration::from_millis;
+        assert_eq!(10.0, change_percent(ms(100), ms(110)).round());
+        assert_eq!(-50.0, change_percent(ms(100), ms(50)).round());
+    }
+
+    #[test]
+    fn format_duration_works() {
+        assert_eq!("999 ns", format_duration(Duration::from_nanos(999)));
+        assert_eq!("1.500 µs", format_duration(Duration::from_nanos(1500)));
+        assert_eq!("20.000 ms", format_duration(Duration::from_millis(20)));
+        assert_eq!("2.000 s", format_duration(Duration::from_secs(2)));
+    }
+}
@@ -0,0 +1,205 @@
+use crate::output::input_hash;
+use crate::{Part, Solver};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Directory to keep cached answers in, overriding the default.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+#[derive(Error, Debug)]
+pub enum CacheError {
+    #[error("Unable to read cached answer from {0}, {1}")]
+    Read(PathBuf, std::io::Error),
+    #[error("Unable to write cached answer to {0}, {1}")]
+    Write(PathBuf, std::io::Error),
+    #[error("Unable to clear cache at {0}, {1}")]
+    Clear(PathBuf, std::io::Error),
+}
+
+/// Answers already worked out, one file per day, part, solver version and
+/// input, so unchanged inputs aren't solved again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache { dir: dir.into() }
+    }
+
+    /// [`CACHE_DIR_VAR`] if set, otherwise `aoc2022` in the user's cache
+    /// directory. `None` if there's nowhere to put it.
+    pub fn from_env() -> Option<Self> {
+        if let Some(dir) = std::env::var_os(CACHE_DIR_VAR) {
+            return Some(Cache::new(dir));
+        }
+        Some(Cache::new(user_cache_dir()?.join("aoc2022")))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path(&self, s: &dyn Solver, part: Part, input: &str) -> PathBuf {
+        self.dir.join(format!(
+            "day{}-part{}-v{}-{}",
+            s.day(),
+            part,
+            s.version(),
+            input_hash(input)
+        ))
+    }
+
+    pub fn get(
+        &self,
+        s: &dyn Solver,
+        part: Part,
+        input: &str,
+    ) -> Result<Option<String>, CacheError> {
+        let path = self.path(s, part, input);
+        match std::fs::read_to_string(&path) {
+            Ok(answer) => Ok(Some(answer)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(CacheError::Read(path, e)),
+        }
+    }
+
+    pub fn put(
+        &self,
+        s: &dyn Solver,
+        part: Part,
+        input: &str,
+        answer: &str,
+    ) -> Result<(), CacheError> {
+        let path = self.path(s, part, input);
+        // written aside and renamed, so a reader never sees half an answer
+        let tmp = path.with_extension(format!("tmp{}", std::process::id()));
+        let write = || -> std::io::Result<()> {
+            std::fs::create_dir_all(&self.dir)?;
+            std::fs::write(&tmp, answer)?;
+            std::fs::rename(&tmp, &path)
+        };
+        write().map_err(|e| CacheError::Write(path.clone(), e))
+    }
+
+    /// Removes every cached answer, returning how many there were. Other
+    /// files in the directory are left alone.
+    pub fn clear(&self) -> Result<usize, CacheError> {
+        let clear_err = |e| CacheError::Clear(self.dir.clone(), e);
+        let entries = match std::fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(clear_err(e)),
+        };
+        let mut num_answers = 0;
+        for entry in entries {
+            let entry = entry.map_err(clear_err)?;
+            let is_answer = entry.file_name().to_str().is_some_and(is_answer_file_name);
+            if is_answer && entry.file_type().map_err(clear_err)?.is_file() {
+                std::fs::remove_file(entry.path()).map_err(clear_err)?;
+                num_answers += 1;
+            }
+        }
+        Ok(num_answers)
+    }
+}
+
+/// `$XDG_CACHE_HOME`, otherwise `~/.cache`.
+pub fn user_cache_dir() -> Option<PathBuf> {
+    match std::env::var_os("XDG_CACHE_HOME") {
+        Some(dir) => Some(PathBuf::from(dir)),
+        None => Some(PathBuf::from(std::env::var_os("HOME")?).join(".cache")),
+    }
+}
+
+/// Whether `name` is `day{N}-part{P}-v{V}-{sha1}`, as written by [`Cache::put`].
+fn is_answer_file_name(name: &str) -> bool {
+    let numbered = |s: &str, prefix: &str| {
+        s.strip_prefix(prefix)
+            .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+    };
+    match name.split('-').collect::<Vec<_>>()[..] {
+        [day, part, version, hash] => {
+            numbered(day, "day")
+                && numbered(part, "part")
+                && numbered(version, "v")
+                && hash.len() == 40
+                && hash.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Example, Solution};
+
+    struct Count;
+
+    impl Solution for Count {
+        const DAY: u8 = 0;
+        const TITLE: &'static str = "Count";
+        const EXAMPLES: &'static [Example] = &[Example::new("a\nb\n", "2", "4")];
+        type Parsed = usize;
+        type Answer1 = usize;
+        type Answer2 = usize;
+        type Error = std::convert::Infallible;
+
+        fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+            Ok(input.lines().count())
+        }
+
+        fn part1(parsed: &Self::Parsed) -> Result<Self::Answer1, Self::Error> {
+            Ok(*parsed)
+        }
+
+        fn part2(parsed: &Self::Parsed) -> Result<Self::Answer2, Self::Error> {
+            Ok(*parsed * 2)
+        }
+    }
+
+    #[test]
+    fn cache_works() {
+        let dir = std::env::temp_dir().join(format!("aoc-core-cache-{}", std::process::id()));
+        let cache = Cache::new(&dir);
+        assert_eq!(0, cache.clear().unwrap());
+        assert_eq!(None, cache.get(&Count, Part::One, "a\nb").unwrap());
+
+        cache.put(&Count, Part::One, "a\nb", "2").unwrap();
+        cache.put(&Count, Part::Two, "a\nb", "#.\n.#").unwrap();
+        assert_eq!(
+            Some("2".to_string()),
+            cache.get(&Count, Part::One, "a\nb").unwrap()
+        );
+        assert_eq!(
+            Some("#.\n.#".to_string()),
+            cache.get(&Count, Part::Two, "a\nb").unwrap()
+        );
+        assert_eq!(None, cache.get(&Count, Part::One, "a\nc").unwrap());
+
+        std::fs::write(dir.join("notes.txt"), "keep").unwrap();
+        std::fs::create_dir(dir.join("day1-part1-v1-sub")).unwrap();
+        assert_eq!(2, cache.clear().unwrap());
+        assert_eq!(None, cache.get(&Count, Part::One, "a\nb").unwrap());
+        assert!(dir.join("notes.txt").exists());
+        assert!(dir.join("day1-part1-v1-sub").exists());
+        assert_eq!(0, cache.clear().unwrap());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn is_answer_file_name_works() {
+        let hash = input_hash("a\nb");
+        assert!(is_answer_file_name(&format!("day12-part2-v3-{}", hash)));
+        assert!(!is_answer_file_name(&format!(
+            "day12-part2-v3-{}.tmp42",
+            hash
+        )));
+        assert!(!is_answer_file_name(&format!("day-part2-v3-{}", hash)));
+        assert!(!is_answer_file_name("day1-part1-v1-abc"));
+        assert!(!is_answer_file_name("responses"));
+        assert!(!is_answer_file_name("last-request"));
+    }
+}
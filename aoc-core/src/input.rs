@@ -0,0 +1,302 @@
+use crate::Format;
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Directory holding puzzle inputs as `dayN.txt` or `dayN/input.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Error, Debug)]
+pub enum InputError {
+    #[error("Unable to read input from {0}, {1}")]
+    Read(PathBuf, std::io::Error),
+    #[error("Unable to read input from stdin, {0}")]
+    Stdin(std::io::Error),
+    #[error(
+        "No input found for day {day}, tried {}. Use --input PATH, --input - for stdin, or set {INPUT_DIR_VAR}",
+        display_paths(.tried)
+    )]
+    NotFound { day: u8, tried: Vec<PathBuf> },
+    #[error("Missing value for {0}")]
+    MissingValue(String),
+    #[error("Unexpected argument {0:?}")]
+    UnexpectedArg(String),
+    #[error("Invalid value for {0}, {1}")]
+    InvalidValue(String, String),
+}
+
+fn display_paths(paths: &[PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|p| p.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// Search [`INPUT_DIR_VAR`] and then the day's crate for its input.
+    #[default]
+    Default,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl std::str::FromStr for InputSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "-" => Ok(InputSource::Stdin),
+            _ => Ok(InputSource::Path(s.into())),
+        }
+    }
+}
+
+impl InputSource {
+    /// The input as a string, searching [`default_input_paths`] by default.
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        match self {
+            InputSource::Default => read_first_found(day, &default_input_paths(day)),
+            InputSource::Path(path) => read_path(path),
+            InputSource::Stdin => {
+                let mut buffer = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut buffer)
+                    .map_err(InputError::Stdin)?;
+                Ok(buffer)
+            }
+        }
+    }
+
+    /// As [`read`](Self::read), but searching [`day_binary_input_paths`].
+    pub fn read_in_day_binary(&self, day: u8) -> Result<String, InputError> {
+        match self {
+            InputSource::Default => read_first_found(day, &day_binary_input_paths(day)),
+            source => source.read(day),
+        }
+    }
+
+    /// Opens the input to read a line at a time rather than all at once,
+    /// searching [`day_binary_input_paths`] by default.
+    pub fn open_in_day_binary(&self, day: u8) -> Result<Box<dyn BufRead>, InputError> {
+        let path = match self {
+            InputSource::Default => {
+                let tried = day_binary_input_paths(day);
+                let found = tried.iter().find(|p| p.is_file()).cloned();
+                found.ok_or(InputError::NotFound { day, tried })?
+            }
+            InputSource::Path(path) => path.clone(),
+            InputSource::Stdin => return Ok(Box::new(std::io::stdin().lock())),
+        };
+        match std::fs::File::open(&path) {
+            Ok(file) => Ok(Box::new(std::io::BufReader::new(file))),
+            Err(e) => Err(InputError::Read(path, e)),
+        }
+    }
+}
+
+/// Paths searched for a day's input when no source is given, in order.
+pub fn default_input_paths(day: u8) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    if let Some(dir) = std::env::var_os(INPUT_DIR_VAR) {
+        let dir = PathBuf::from(dir);
+        paths.push(dir.join(format!("day{}.txt", day)));
+        paths.push(dir.join(format!("day{}", day)).join("input.txt"));
+    }
+    paths.push(PathBuf::from(format!("./day{}/src/input.txt", day)));
+    paths
+}
+
+/// Paths a day's own binary searches when no source is given, the
+/// [`default_input_paths`] then `./src/input.txt`, since the binary is often
+/// started from inside its own crate.
+pub fn day_binary_input_paths(day: u8) -> Vec<PathBuf> {
+    let mut paths = default_input_paths(day);
+    paths.push(PathBuf::from("./src/input.txt"));
+    paths
+}
+
+/// The first of [`default_input_paths`] that exists.
+pub fn find_default_input(day: u8) -> Option<PathBuf> {
+    default_input_paths(day).into_iter().find(|p| p.is_file())
+}
+
+pub fn read_first_found(day: u8, paths: &[PathBuf]) -> Result<String, InputError> {
+    for path in paths {
+        match std::fs::read_to_string(path) {
+            Ok(buffer) => return Ok(buffer),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(InputError::Read(path.clone(), e)),
+        }
+    }
+    Err(InputError::NotFound {
+        day,
+        tried: paths.to_vec(),
+    })
+}
+
+fn read_path(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|e| InputError::Read(path.to_path_buf(), e))
+}
+
+/// Arguments accepted by each day's own binary.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DayArgs {
+    pub input: InputSource,
+    pub format: Format,
+}
+
+impl DayArgs {
+    pub fn from_env() -> Result<Self, InputError> {
+        Self::parse(std::env::args().skip(1))
+    }
+
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, InputError> {
+        let mut day_args = DayArgs::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let (name, value) = match arg.split_once('=') {
+                Some((name, value)) if name.starts_with("--") => (name, value.to_string()),
+                _ => match arg.as_str() {
+                    "-i" | "--input" | "-f" | "--format" => {
+                        let value = args.next().ok_or(InputError::MissingValue(arg.clone()))?;
+                        (arg.as_str(), value)
+                    }
+                    _ => return Err(InputError::UnexpectedArg(arg)),
+                },
+            };
+            match name {
+                "-i" | "--input" => {
+                    let Ok(input) = value.parse::<InputSource>();
+                    day_args.input = input;
+                }
+                "-f" | "--format" => {
+                    day_args.format =
+                        value
+                            .parse()
+                            .map_err(|e: crate::output::ParseFormatError| {
+                                InputError::InvalidValue(name.to_string(), e.to_string())
+                            })?;
+                }
+                _ => return Err(InputError::UnexpectedArg(arg)),
+            }
+        }
+        Ok(day_args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(v: &[&str]) -> Vec<String> {
+        v.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn input_source_from_str_works() {
+        assert_eq!(InputSource::Stdin, "-".parse().unwrap());
+        assert_eq!(
+            InputSource::Path("a/b.txt".into()),
+            "a/b.txt".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn day_args_parse_works() {
+        assert_eq!(
+            InputSource::Default,
+            DayArgs::parse(args(&[])).unwrap().input
+        );
+        assert_eq!(
+            InputSource::Path("x.txt".into()),
+            DayArgs::parse(args(&["--input", "x.txt"])).unwrap().input
+        );
+        assert_eq!(
+            InputSource::Path("x.txt".into()),
+            DayArgs::parse(args(&["--input=x.txt"])).unwrap().input
+        );
+        assert_eq!(
+            InputSource::Stdin,
+            DayArgs::parse(args(&["-i", "-"])).unwrap().input
+        );
+        assert!(matches!(
+            DayArgs::parse(args(&["--input"])),
+            Err(InputError::MissingValue(_))
+        ));
+        assert!(matches!(
+            DayArgs::parse(args(&["x.txt"])),
+            Err(InputError::UnexpectedArg(_))
+        ));
+        assert!(matches!(
+            DayArgs::parse(args(&["--output=x.txt"])),
+            Err(InputError::UnexpectedArg(_))
+        ));
+    }
+
+    #[test]
+    fn day_args_parse_format_works() {
+        assert_eq!(Format::Text, DayArgs::parse(args(&[])).unwrap().format);
+        let day_args = DayArgs::parse(args(&["--format", "json", "-i", "x.txt"])).unwrap();
+        assert_eq!(Format::Json, day_args.format);
+        assert_eq!(InputSource::Path("x.txt".into()), day_args.input);
+        assert_eq!(
+            Format::Json,
+            DayArgs::parse(args(&["--format=json"])).unwrap().format
+        );
+        assert!(matches!(
+            DayArgs::parse(args(&["-f", "yaml"])),
+            Err(InputError::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn read_first_found_works() {
+        let dir = std::env::temp_dir().join(format!("aoc-core-input-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let missing = dir.join("missing.txt");
+        let present = dir.join("present.txt");
+        std::fs::write(&present, "1\n2\n").unwrap();
+
+        let input = read_first_found(1, &[missing.clone(), present.clone()]).unwrap();
+        assert_eq!("1\n2\n", input);
+
+        let err = read_first_found(3, std::slice::from_ref(&missing)).unwrap_err();
+        assert!(matches!(err, InputError::NotFound { day: 3, .. }));
+        assert!(err.to_string().contains("missing.txt"));
+        assert!(err.to_string().contains(INPUT_DIR_VAR));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn open_in_day_binary_works() {
+        let dir = std::env::temp_dir().join(format!("aoc-core-open-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("input.txt");
+        std::fs::write(&path, "1\n2\n").unwrap();
+
+        let source = InputSource::Path(path.clone());
+        let reader = source.open_in_day_binary(1).unwrap();
+        assert_eq!(2, reader.lines().count());
+        assert_eq!("1\n2\n", source.read_in_day_binary(1).unwrap());
+
+        let source = InputSource::Path(dir.join("missing.txt"));
+        assert!(matches!(
+            source.open_in_day_binary(1),
+            Err(InputError::Read(_, _))
+        ));
+        assert_eq!(
+            Some(&PathBuf::from("./src/input.txt")),
+            day_binary_input_paths(1).last()
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn default_input_paths_ends_with_crate_input() {
+        let paths = default_input_paths(7);
+        assert_eq!(Some(&PathBuf::from("./day7/src/input.txt")), paths.last());
+    }
+}
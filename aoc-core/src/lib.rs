@@ -0,0 +1,271 @@
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod input;
+pub mod output;
+pub mod parse;
+
+pub use answers::{Answers, Verdict};
+pub use cache::Cache;
+pub use input::{DayArgs, InputError, InputSource};
+pub use output::{Format, Record};
+pub use parse::ParseError;
+use std::time::Duration;
+use thiserror::Error;
+
+pub type BoxedError = Box<dyn std::error::Error + Send + Sync>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One = 1,
+    Two = 2,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+#[derive(Error, Debug)]
+#[error("No part {0:?}, expected 1 or 2")]
+pub struct ParsePartError(String);
+
+impl std::str::FromStr for Part {
+    type Err = ParsePartError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(ParsePartError(s.to_string())),
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = ParsePartError;
+
+    fn try_from(n: u8) -> Result<Self, Self::Error> {
+        match n {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(ParsePartError(n.to_string())),
+        }
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", *self as u8)
+    }
+}
+
+/// An example input from a puzzle's text, with the answers the text gives
+/// for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Example {
+    pub input: &'static str,
+    /// `None` when the puzzle doesn't give the answer for this example.
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Example {
+    pub const fn new(input: &'static str, part1: &'static str, part2: &'static str) -> Self {
+        Example {
+            input,
+            part1: Some(part1),
+            part2: Some(part2),
+        }
+    }
+
+    pub fn answer(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }
+    }
+}
+
+/// A single day's puzzle, split into parsing the input and solving each part
+/// from the parsed representation.
+pub trait Solution {
+    /// Day of the advent calendar, starting at 1.
+    const DAY: u8;
+    /// Puzzle title, as shown on the advent calendar.
+    const TITLE: &'static str;
+    /// Bumped whenever a change could alter the answers, so answers cached
+    /// from the old version aren't used.
+    const VERSION: u32 = 1;
+    /// The puzzle's examples, see `aoc run --example`.
+    const EXAMPLES: &'static [Example];
+
+    type Parsed;
+    type Answer1: std::fmt::Display;
+    type Answer2: std::fmt::Display;
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error>;
+    fn part1(parsed: &Self::Parsed) -> Result<Self::Answer1, Self::Error>;
+    fn part2(parsed: &Self::Parsed) -> Result<Self::Answer2, Self::Error>;
+}
+
+/// Parses `input` and solves one part, returning the answer as text.
+pub fn solve<S: Solution>(part: Part, input: &str) -> Result<String, BoxedError> {
+    let parsed = S::parse(input)?;
+    let answer = match part {
+        Part::One => S::part1(&parsed)?.to_string(),
+        Part::Two => S::part2(&parsed)?.to_string(),
+    };
+    Ok(answer)
+}
+
+/// Object safe view of a [`Solution`], so days can be looked up and run at
+/// runtime without knowing their parsed or answer types.
+pub trait Solver: Sync {
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn version(&self) -> u32;
+    fn examples(&self) -> &'static [Example];
+    fn solve(&self, part: Part, input: &str) -> Result<String, BoxedError>;
+    /// Parses `input` without solving either part.
+    fn parse_only(&self, input: &str) -> Result<(), BoxedError>;
+    /// Times each stage of solving `input`, see [`bench::measure`].
+    fn measure(&self, input: &str, budget: Duration) -> Result<bench::Timings, BoxedError>;
+}
+
+impl<S: Solution + Sync> Solver for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn version(&self) -> u32 {
+        S::VERSION
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        S::EXAMPLES
+    }
+
+    fn solve(&self, part: Part, input: &str) -> Result<String, BoxedError> {
+        solve::<S>(part, input)
+    }
+
+    fn parse_only(&self, input: &str) -> Result<(), BoxedError> {
+        S::parse(input)?;
+        Ok(())
+    }
+
+    fn measure(&self, input: &str, budget: Duration) -> Result<bench::Timings, BoxedError> {
+        bench::measure::<S>(input, budget)
+    }
+}
+
+/// Sends the solvers' logs to stderr, filtered by `RUST_LOG`.
+pub fn init_logger() {
+    let _ = env_logger::try_init();
+}
+
+/// Runs a day's own binary: reads the input named on the command line, or
+/// the default input, and prints the answers to both parts.
+pub fn run<S: Solution>() -> std::process::ExitCode {
+    init_logger();
+    match try_run::<S>() {
+        Ok(()) => std::process::ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::ExitCode::FAILURE
+        }
+    }
+}
+
+fn try_run<S: Solution>() -> Result<(), BoxedError> {
+    let args = DayArgs::from_env()?;
+    let input = args.input.read_in_day_binary(S::DAY)?;
+    for part in Part::ALL {
+        Record::timed(S::DAY, part, &input, || solve::<S>(part, &input))?.print(args.format);
+    }
+    Ok(())
+}
+
+pub fn print_answer(day: u8, part: Part, answer: &str) {
+    match answer.contains('\n') {
+        true => println!("Day {0} part {1} =\n{2}", day, part, answer),
+        false => println!("Day {0} part {1} = {2}", day, part, answer),
+    }
+}
+
+/// Reads the day's input from the default locations.
+pub fn read_input(day: u8) -> Result<String, InputError> {
+    InputSource::Default.read(day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        const TITLE: &'static str = "Sum";
+        const EXAMPLES: &'static [Example] = &[Example::new("1\n2\n3\n", "6", "6")];
+        type Parsed = Vec<i64>;
+        type Answer1 = i64;
+        type Answer2 = i64;
+        type Error = std::num::ParseIntError;
+
+        fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+            input.lines().map(|l| l.parse::<i64>()).collect()
+        }
+
+        fn part1(parsed: &Self::Parsed) -> Result<Self::Answer1, Self::Error> {
+            Ok(parsed.iter().sum())
+        }
+
+        fn part2(parsed: &Self::Parsed) -> Result<Self::Answer2, Self::Error> {
+            Ok(parsed.iter().product())
+        }
+    }
+
+    #[test]
+    fn solve_works() {
+        assert_eq!("6", solve::<Sum>(Part::One, "1\n2\n3").unwrap());
+        assert_eq!("6", solve::<Sum>(Part::Two, "1\n2\n3").unwrap());
+        assert_eq!("-4", solve::<Sum>(Part::Two, "-1\n4").unwrap());
+        assert!(solve::<Sum>(Part::One, "1\nx").is_err());
+    }
+
+    #[test]
+    fn solver_works() {
+        let solver: &dyn Solver = &Sum;
+        assert_eq!(0, solver.day());
+        assert_eq!("Sum", solver.title());
+        assert_eq!(Some("6"), solver.examples()[0].answer(Part::Two));
+        assert_eq!("24", solver.solve(Part::Two, "2\n3\n4").unwrap());
+        assert!(solver.parse_only("2\nx").is_err());
+    }
+
+    #[test]
+    fn part_display_works() {
+        assert_eq!("1", Part::One.to_string());
+        assert_eq!("2", Part::Two.to_string());
+    }
+
+    #[test]
+    fn part_from_str_works() {
+        assert_eq!(Part::One, "1".parse::<Part>().unwrap());
+        assert_eq!(Part::Two, "2".parse::<Part>().unwrap());
+        assert!("3".parse::<Part>().is_err());
+        assert!("one".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn part_try_from_works() {
+        assert_eq!(Part::Two, Part::try_from(2).unwrap());
+        assert!(Part::try_from(0).is_err());
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{BoxedError, Part};
+use sha1::{Digest, Sha1};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// How answers are printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// `Day 7 part 1 = 1141028`
+    #[default]
+    Text,
+    /// One JSON object per answer and line, see [`Record::to_json`].
+    Json,
+}
+
+#[derive(Error, Debug)]
+#[error("No format {0:?}, expected text or json")]
+pub struct ParseFormatError(String);
+
+impl std::str::FromStr for Format {
+    type Err = ParseFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(ParseFormatError(s.to_string())),
+        }
+    }
+}
+
+/// The answer to one part, with how long it took and which input it was for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    /// Time taken to parse the input and solve the part.
+    pub duration: Duration,
+    /// See [`input_hash`].
+    pub input_hash: String,
+}
+
+impl Record {
+    /// Runs `solve` to get the answer to `part` of `input`, timing it.
+    pub fn timed<F>(day: u8, part: Part, input: &str, solve: F) -> Result<Self, BoxedError>
+    where
+        F: FnOnce() -> Result<String, BoxedError>,
+    {
+        let start = Instant::now();
+        let answer = solve()?;
+        Ok(Record {
+            day,
+            part,
+            answer,
+            duration: start.elapsed(),
+            input_hash: input_hash(input),
+        })
+    }
+
+    /// `{"day":7,"part":1,"answer":"1141028","duration":0.0012,"input_hash":"..."}`,
+    /// with the duration in seconds.
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"day\":{},\"part\":{},\"answer\":{},\"duration\":{},\"input_hash\":{}}}",
+            self.day,
+            self.part,
+            json_string(&self.answer),
+            self.duration.as_secs_f64(),
+            json_string(&self.input_hash)
+        )
+    }
+
+    pub fn print(&self, format: Format) {
+        match format {
+            Format::Text => crate::print_answer(self.day, self.part, &self.answer),
+            Format::Json => println!("{}", self.to_json()),
+        }
+    }
+}
+
+/// Reads through to `inner`, hashing what's read as [`input_hash`] would,
+/// for inputs read a line at a time rather than held in memory.
+pub struct HashingReader<R> {
+    inner: R,
+    hasher: Sha1,
+}
+
+impl<R: std::io::BufRead> HashingReader<R> {
+    pub fn new(inner: R) -> Self {
+        HashingReader {
+            inner,
+            hasher: Sha1::new(),
+        }
+    }
+
+    /// Hash of everything read so far.
+    pub fn input_hash(&self) -> String {
+        hex(&self.hasher.clone().finalize())
+    }
+}
+
+impl<R: std::io::BufRead> std::io::Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let num_bytes = self.inner.read(buf)?;
+        self.hasher.update(&buf[..num_bytes]);
+        Ok(num_bytes)
+    }
+}
+
+impl<R: std::io::BufRead> std::io::BufRead for HashingReader<R> {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        // the buffer is still filled, so this doesn't read any further
+        if let Ok(buf) = self.inner.fill_buf() {
+            self.hasher.update(&buf[..amt.min(buf.len())]);
+        }
+        self.inner.consume(amt);
+    }
+}
+
+/// Hex SHA-1 of the input, to tell apart results for different inputs.
+pub fn input_hash(input: &str) -> String {
+    hex(&Sha1::digest(input.as_bytes()))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_to_json_works() {
+        let record = Record {
+            day: 10,
+            part: Part::Two,
+            answer: "#.\n.\"#\"".to_string(),
+            duration: Duration::from_micros(1500),
+            input_hash: input_hash("noop\n"),
+        };
+        assert_eq!(
+            "{\"day\":10,\"part\":2,\"answer\":\"#.\\n.\\\"#\\\"\",\"duration\":0.0015,\
+             \"input_hash\":\"d12dac5f63c9821e83e484e04a98efe73fd18861\"}",
+            record.to_json()
+        );
+    }
+
+    #[test]
+    fn input_hash_works() {
+        assert_eq!("da39a3ee5e6b4b0d3255bfef95601890afd80709", input_hash(""));
+        assert_ne!(input_hash("1\n2"), input_hash("1\n3"));
+    }
+
+    #[test]
+    fn hashing_reader_works() {
+        use std::io::BufRead;
+        let input = "1000\n2000\n\n3000";
+        let mut reader = HashingReader::new(std::io::BufReader::with_capacity(4, input.as_bytes()));
+        assert_eq!(4, (&mut reader).lines().count());
+        assert_eq!(input_hash(input), reader.input_hash());
+    }
+
+    #[test]
+    fn format_from_str_works() {
+        assert_eq!(Format::Json, "json".parse().unwrap());
+        assert_eq!(Format::Text, "text".parse().unwrap());
+        assert!("yaml".parse::<Format>().is_err());
+    }
+}
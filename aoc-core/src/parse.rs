@@ -0,0 +1,181 @@
+use nom::error::{ContextError, ErrorKind, FromExternalError};
+
+/// `nom::IResult` using [`NomError`], for parsers that report where they failed.
+pub type IResult<'a, O> = nom::IResult<&'a str, O, NomError<'a>>;
+
+/// nom error that keeps the input remaining at the failure and the innermost
+/// `context` describing what was expected there.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NomError<'a> {
+    pub input: &'a str,
+    pub kind: ErrorKind,
+    pub expected: Option<&'static str>,
+}
+
+impl<'a> nom::error::ParseError<&'a str> for NomError<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        NomError {
+            input,
+            kind,
+            expected: None,
+        }
+    }
+
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn or(self, other: Self) -> Self {
+        // report the alternative that got furthest into the input
+        match other.input.len() <= self.input.len() {
+            true => other,
+            false => self,
+        }
+    }
+}
+
+impl<'a> ContextError<&'a str> for NomError<'a> {
+    fn add_context(_input: &'a str, ctx: &'static str, mut other: Self) -> Self {
+        other.expected.get_or_insert(ctx);
+        other
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for NomError<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _e: E) -> Self {
+        nom::error::ParseError::from_error_kind(input, kind)
+    }
+}
+
+/// A parse failure on one line of the input, rendered as a caret diagnostic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line number, starting at 1.
+    pub line: usize,
+    /// Column in characters, starting at 1.
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Locates `err` within `text`, the line it was parsed from.
+    pub fn new(line: usize, text: &str, err: NomError<'_>) -> Self {
+        let start = text.as_ptr() as usize;
+        let at = err.input.as_ptr() as usize;
+        let offset = match (start..=start + text.len()).contains(&at) {
+            true => at - start,
+            false => text.len().saturating_sub(err.input.len()),
+        };
+        let text = text.trim_end_matches(['\n', '\r']);
+        let offset = offset.min(text.len());
+        let column = text[..offset].chars().count() + 1;
+        let expected = match err.expected {
+            Some(ctx) => ctx.to_string(),
+            None => describe_kind(err.kind).to_string(),
+        };
+        ParseError {
+            line,
+            column,
+            text: text.to_string(),
+            expected,
+        }
+    }
+
+    /// The text at the error position, up to the next whitespace.
+    pub fn found(&self) -> &str {
+        let rest = self
+            .text
+            .char_indices()
+            .nth(self.column - 1)
+            .map_or("", |(idx, _)| &self.text[idx..]);
+        rest.split_whitespace().next().unwrap_or("")
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let found = match self.found() {
+            "" => "end of line".to_string(),
+            s => format!("{:?}", s),
+        };
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(
+            f,
+            "line {0}, column {1}: expected {2}, found {3}",
+            self.line, self.column, self.expected, found
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(f, "{} | {}^", gutter, " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn describe_kind(kind: ErrorKind) -> &'static str {
+    match kind {
+        ErrorKind::Tag => "a keyword",
+        ErrorKind::Digit => "digits",
+        ErrorKind::Char | ErrorKind::OneOf => "a specific character",
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace",
+        ErrorKind::Eof => "end of line",
+        ErrorKind::TakeWhile1 => "at least one valid character",
+        ErrorKind::MapRes | ErrorKind::MapOpt => "a value in range",
+        ErrorKind::Alt => "one of several alternatives",
+        _ => "valid input",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{Finish, Parser, bytes::complete::tag, character::complete::u8, error::context};
+
+    fn parse_move(i: &str) -> IResult<'_, u8> {
+        nom::sequence::preceded(context("\"move \"", tag("move ")), context("a number", u8))
+            .parse(i)
+    }
+
+    #[test]
+    fn parse_error_new_works() {
+        let line = "move x from 2";
+        let err = parse_move(line).finish().unwrap_err();
+        let pe = ParseError::new(3, line, err);
+
+        assert_eq!(3, pe.line);
+        assert_eq!(6, pe.column);
+        assert_eq!("a number", pe.expected);
+        assert_eq!("x", pe.found());
+
+        let err = parse_move("mvoe 1\n").finish().unwrap_err();
+        let pe = ParseError::new(1, "mvoe 1\n", err);
+        assert_eq!(1, pe.column);
+        assert_eq!("\"move \"", pe.expected);
+        assert_eq!("mvoe 1", pe.text);
+    }
+
+    #[test]
+    fn parse_error_display_works() {
+        let line = "move x from 2";
+        let err = parse_move(line).finish().unwrap_err();
+        let pe = ParseError::new(12, line, err);
+
+        let r = "\
+line 12, column 6: expected a number, found \"x\"
+   |
+12 | move x from 2
+   |      ^";
+        assert_eq!(r, pe.to_string());
+    }
+
+    #[test]
+    fn parse_error_at_end_of_line_works() {
+        let line = "move ";
+        let err = parse_move(line).finish().unwrap_err();
+        let pe = ParseError::new(1, line, err);
+
+        assert_eq!(6, pe.column);
+        assert!(pe.to_string().contains("found end of line"));
+    }
+}
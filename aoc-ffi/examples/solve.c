@@ -0,0 +1,59 @@
+/* Solves a day's puzzle from C, e.g. after `cargo build -p aoc-ffi`:
+ *
+ *   cc aoc-ffi/examples/solve.c -Iaoc-ffi/include -Ltarget/debug -laoc_ffi -o solve
+ *   LD_LIBRARY_PATH=target/debug ./solve 1 2 day1/src/input.txt
+ */
+
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "aoc_ffi.h"
+
+int main(int argc, char **argv) {
+  if (argc != 4) {
+    fprintf(stderr, "usage: %s DAY PART INPUT\n", argv[0]);
+    return 2;
+  }
+  if (aoc_abi_version() != AOC_ABI_VERSION) {
+    fprintf(stderr, "library ABI %u, header ABI %u\n", aoc_abi_version(), AOC_ABI_VERSION);
+    return 1;
+  }
+
+  FILE *file = fopen(argv[3], "rb");
+  if (file == NULL) {
+    perror(argv[3]);
+    return 1;
+  }
+  fseek(file, 0, SEEK_END);
+  long len = ftell(file);
+  rewind(file);
+  uint8_t *input = malloc(len);
+  if (fread(input, 1, len, file) != (size_t)len) {
+    perror(argv[3]);
+    return 1;
+  }
+  fclose(file);
+
+  /* Most answers fit, otherwise retry with the length asked for. */
+  AocBuffer out = {.ptr = malloc(32), .cap = 32, .len = 0};
+  AocStatus status = aoc_solve(atoi(argv[1]), atoi(argv[2]), input, len, &out);
+  if (status == AOC_STATUS_BUFFER_TOO_SMALL) {
+    out.cap = out.len + 1;
+    out.ptr = realloc(out.ptr, out.cap);
+    status = aoc_solve(atoi(argv[1]), atoi(argv[2]), input, len, &out);
+  }
+
+  switch (status) {
+  case AOC_STATUS_OK:
+    printf("%s\n", out.ptr);
+    break;
+  case AOC_STATUS_FAILED:
+    fprintf(stderr, "aoc %s: %s\n", aoc_version(), out.ptr);
+    break;
+  default:
+    fprintf(stderr, "aoc %s: %s\n", aoc_version(), aoc_status_message(status));
+  }
+  free(out.ptr);
+  free(input);
+  return status == AOC_STATUS_OK ? 0 : 1;
+}
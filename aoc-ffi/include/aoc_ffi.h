@@ -0,0 +1,101 @@
+#ifndef AOC_FFI_H
+#define AOC_FFI_H
+
+/* Generated by cbindgen from src/lib.rs, don't edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Bumped whenever a function's signature or meaning changes.
+ */
+#define AOC_ABI_VERSION 1
+
+/**
+ * Result of a call.
+ */
+typedef enum AocStatus {
+  AOC_STATUS_OK = 0,
+  AOC_STATUS_NO_SUCH_DAY = 1,
+  AOC_STATUS_NO_SUCH_PART = 2,
+  /**
+   * The input isn't valid UTF-8.
+   */
+  AOC_STATUS_INVALID_UTF8 = 3,
+  /**
+   * The input couldn't be parsed or solved, the buffer holds the reason.
+   */
+  AOC_STATUS_FAILED = 4,
+  /**
+   * The buffer is too small for the answer, which was truncated. Its
+   * `len` is the size needed, less the NUL.
+   */
+  AOC_STATUS_BUFFER_TOO_SMALL = 5,
+  AOC_STATUS_NULL_POINTER = 6,
+  /**
+   * The solver panicked, which is a bug.
+   */
+  AOC_STATUS_PANIC = 7,
+} AocStatus;
+
+/**
+ * A buffer owned by the caller that answers and error messages are
+ * written to, NUL terminated.
+ */
+typedef struct AocBuffer {
+  char *ptr;
+  /**
+   * Bytes available at `ptr`, including room for the NUL.
+   */
+  size_t cap;
+  /**
+   * Set to the length of the text written, without the NUL, even when it
+   * didn't fit.
+   */
+  size_t len;
+} AocBuffer;
+
+/**
+ * Version of the solutions, e.g. "0.1.0". The string is static.
+ */
+const char *aoc_version(void);
+
+/**
+ * [`AOC_ABI_VERSION`] of the library, to check against the header's.
+ */
+uint32_t aoc_abi_version(void);
+
+/**
+ * Describes a status, e.g. "no such day". The string is static.
+ */
+const char *aoc_status_message(int32_t status);
+
+/**
+ * Solves `part` of `day` for the `len` bytes of UTF-8 at `input`, writing
+ * the answer to `out`. On `AOC_STATUS_FAILED` `out` holds the reason.
+ *
+ * # Safety
+ *
+ * `input` must point to `len` readable bytes, or may be null if `len` is 0.
+ * `out` must point to an `AocBuffer` whose `ptr` has `cap` writable bytes.
+ */
+enum AocStatus aoc_solve(uint8_t day,
+                         uint8_t part,
+                         const uint8_t *input,
+                         size_t len,
+                         struct AocBuffer *out);
+
+/**
+ * Parses the `len` bytes of UTF-8 at `input` as `day`'s input, without
+ * solving it. On `AOC_STATUS_FAILED` `out`, if not null, holds the reason.
+ *
+ * # Safety
+ *
+ * As for [`aoc_solve`], except `out` may be null.
+ */
+enum AocStatus aoc_parse(uint8_t day, const uint8_t *input, size_t len, struct AocBuffer *out);
+
+#endif  /* AOC_FFI_H */
@@ -0,0 +1,305 @@
+//! The solutions behind a C ABI, for tools that can't link Rust crates.
+//! `include/aoc_ffi.h` is generated from this file by the build script, see
+//! `examples/solve.c` for its use. To update it after changing the functions,
+//! run `UPDATE_HEADER=1 cargo test -p aoc-ffi header_is_up_to_date`.
+
+use aoc_core::{BoxedError, Part};
+use std::ffi::{CStr, c_char};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+/// Bumped whenever a function's signature or meaning changes.
+pub const AOC_ABI_VERSION: u32 = 1;
+
+/// Result of a call.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AocStatus {
+    Ok = 0,
+    NoSuchDay = 1,
+    NoSuchPart = 2,
+    /// The input isn't valid UTF-8.
+    InvalidUtf8 = 3,
+    /// The input couldn't be parsed or solved, the buffer holds the reason.
+    Failed = 4,
+    /// The buffer is too small for the answer, which was truncated. Its
+    /// `len` is the size needed, less the NUL.
+    BufferTooSmall = 5,
+    NullPointer = 6,
+    /// The solver panicked, which is a bug.
+    Panic = 7,
+}
+
+/// A buffer owned by the caller that answers and error messages are
+/// written to, NUL terminated.
+#[repr(C)]
+pub struct AocBuffer {
+    pub ptr: *mut c_char,
+    /// Bytes available at `ptr`, including room for the NUL.
+    pub cap: usize,
+    /// Set to the length of the text written, without the NUL, even when it
+    /// didn't fit.
+    pub len: usize,
+}
+
+/// Version of the solutions, e.g. "0.1.0". The string is static.
+#[unsafe(no_mangle)]
+pub extern "C" fn aoc_version() -> *const c_char {
+    concat!(env!("CARGO_PKG_VERSION"), "\0").as_ptr().cast()
+}
+
+/// [`AOC_ABI_VERSION`] of the library, to check against the header's.
+#[unsafe(no_mangle)]
+pub extern "C" fn aoc_abi_version() -> u32 {
+    AOC_ABI_VERSION
+}
+
+/// Describes a status, e.g. "no such day". The string is static.
+#[unsafe(no_mangle)]
+pub extern "C" fn aoc_status_message(status: i32) -> *const c_char {
+    let message: &CStr = match status {
+        0 => c"ok",
+        1 => c"no such day",
+        2 => c"no such part, expected 1 or 2",
+        3 => c"input is not valid UTF-8",
+        4 => c"input could not be solved",
+        5 => c"buffer too small",
+        6 => c"null pointer",
+        7 => c"solver panicked",
+        _ => c"unknown status",
+    };
+    message.as_ptr()
+}
+
+/// Solves `part` of `day` for the `len` bytes of UTF-8 at `input`, writing
+/// the answer to `out`. On `AOC_STATUS_FAILED` `out` holds the reason.
+///
+/// # Safety
+///
+/// `input` must point to `len` readable bytes, or may be null if `len` is 0.
+/// `out` must point to an `AocBuffer` whose `ptr` has `cap` writable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    len: usize,
+    out: *mut AocBuffer,
+) -> AocStatus {
+    // SAFETY: the caller guarantees `out` is valid or null
+    let Some(out) = (unsafe { out.as_mut() }) else {
+        return AocStatus::NullPointer;
+    };
+    if out.ptr.is_null() && out.cap > 0 {
+        return AocStatus::NullPointer;
+    }
+    let Some(s) = aoc::solver(day) else {
+        return AocStatus::NoSuchDay;
+    };
+    let Ok(part) = Part::try_from(part) else {
+        return AocStatus::NoSuchPart;
+    };
+    // SAFETY: the caller guarantees `input` has `len` bytes
+    let input = match unsafe { input_str(input, len) } {
+        Ok(input) => input,
+        Err(status) => return status,
+    };
+    // SAFETY: `out` was checked above
+    unsafe { answer_into(out, || s.solve(part, input)) }
+}
+
+/// Parses the `len` bytes of UTF-8 at `input` as `day`'s input, without
+/// solving it. On `AOC_STATUS_FAILED` `out`, if not null, holds the reason.
+///
+/// # Safety
+///
+/// As for [`aoc_solve`], except `out` may be null.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn aoc_parse(
+    day: u8,
+    input: *const u8,
+    len: usize,
+    out: *mut AocBuffer,
+) -> AocStatus {
+    let mut discard = AocBuffer {
+        ptr: std::ptr::null_mut(),
+        cap: 0,
+        len: 0,
+    };
+    // SAFETY: the caller guarantees `out` is valid or null
+    let out = unsafe { out.as_mut() }.unwrap_or(&mut discard);
+    if out.ptr.is_null() && out.cap > 0 {
+        return AocStatus::NullPointer;
+    }
+    let Some(s) = aoc::solver(day) else {
+        return AocStatus::NoSuchDay;
+    };
+    // SAFETY: the caller guarantees `input` has `len` bytes
+    let input = match unsafe { input_str(input, len) } {
+        Ok(input) => input,
+        Err(status) => return status,
+    };
+    // SAFETY: `out` was checked above
+    unsafe { answer_into(out, || s.parse_only(input).map(|()| String::new())) }
+}
+
+/// # Safety
+///
+/// `input` must point to `len` readable bytes, or may be null if `len` is 0.
+unsafe fn input_str<'a>(input: *const u8, len: usize) -> Result<&'a str, AocStatus> {
+    let bytes = match (input.is_null(), len) {
+        (_, 0) => &[],
+        (true, _) => return Err(AocStatus::NullPointer),
+        // SAFETY: checked non-null, and the caller guarantees the length
+        (false, _) => unsafe { std::slice::from_raw_parts(input, len) },
+    };
+    std::str::from_utf8(bytes).map_err(|_| AocStatus::InvalidUtf8)
+}
+
+/// Runs `solve`, writing its answer, error or panic to `out`.
+///
+/// # Safety
+///
+/// `out.ptr` must have `out.cap` writable bytes, or be null if `cap` is 0.
+unsafe fn answer_into<F>(out: &mut AocBuffer, solve: F) -> AocStatus
+where
+    F: FnOnce() -> Result<String, BoxedError>,
+{
+    let (text, status) = match catch_unwind(AssertUnwindSafe(solve)) {
+        Ok(Ok(answer)) => (answer, AocStatus::Ok),
+        Ok(Err(e)) => (e.to_string(), AocStatus::Failed),
+        Err(_) => ("solver panicked".to_string(), AocStatus::Panic),
+    };
+    // SAFETY: passed on from the caller
+    let fits = unsafe { write_out(out, &text) };
+    match (status, fits) {
+        (AocStatus::Ok, false) => AocStatus::BufferTooSmall,
+        _ => status,
+    }
+}
+
+/// Copies as much of `text` as fits into `out`, NUL terminated, returning
+/// whether all of it did.
+///
+/// # Safety
+///
+/// `out.ptr` must have `out.cap` writable bytes, or be null if `cap` is 0.
+unsafe fn write_out(out: &mut AocBuffer, text: &str) -> bool {
+    out.len = text.len();
+    if out.cap == 0 {
+        return text.is_empty();
+    }
+    let num_bytes = text.len().min(out.cap - 1);
+    // SAFETY: `num_bytes + 1 <= cap`, which the caller guarantees is writable
+    unsafe {
+        std::ptr::copy_nonoverlapping(text.as_ptr(), out.ptr.cast::<u8>(), num_bytes);
+        *out.ptr.add(num_bytes) = 0;
+    }
+    num_bytes == text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Calls `aoc_solve` with a buffer of `cap` bytes.
+    fn solve(day: u8, part: u8, input: &[u8], cap: usize) -> (AocStatus, String, usize) {
+        let mut bytes = vec![1 as c_char; cap];
+        let mut out = AocBuffer {
+            ptr: bytes.as_mut_ptr(),
+            cap,
+            len: 0,
+        };
+        let status = unsafe { aoc_solve(day, part, input.as_ptr(), input.len(), &mut out) };
+        let text = match cap {
+            0 => String::new(),
+            _ => unsafe { CStr::from_ptr(out.ptr) }
+                .to_str()
+                .unwrap()
+                .to_string(),
+        };
+        (status, text, out.len)
+    }
+
+    #[test]
+    fn aoc_solve_works() {
+        let input = b"1000\n2000\n\n24000\n\n";
+        assert_eq!((AocStatus::Ok, "24000".into(), 5), solve(1, 1, input, 64));
+        assert_eq!(
+            (AocStatus::BufferTooSmall, "24".into(), 5),
+            solve(1, 1, input, 3)
+        );
+        assert_eq!(
+            (AocStatus::BufferTooSmall, "".into(), 5),
+            solve(1, 1, input, 0)
+        );
+        assert_eq!(AocStatus::NoSuchDay, solve(26, 1, input, 64).0);
+        assert_eq!(AocStatus::NoSuchPart, solve(1, 3, input, 64).0);
+        assert_eq!(AocStatus::InvalidUtf8, solve(1, 1, b"\xff\n", 64).0);
+
+        let (status, message, _) = solve(1, 1, b"x\n", 64);
+        assert_eq!(AocStatus::Failed, status);
+        assert!(message.starts_with("Error reading input on line"));
+    }
+
+    #[test]
+    fn null_pointers_are_rejected() {
+        let input = b"1000\n";
+        let status = unsafe { aoc_solve(1, 1, input.as_ptr(), 5, std::ptr::null_mut()) };
+        assert_eq!(AocStatus::NullPointer, status);
+
+        let mut out = AocBuffer {
+            ptr: std::ptr::null_mut(),
+            cap: 0,
+            len: 0,
+        };
+        let status = unsafe { aoc_solve(1, 1, std::ptr::null(), 5, &mut out) };
+        assert_eq!(AocStatus::NullPointer, status);
+        out.cap = 8;
+        let status = unsafe { aoc_solve(1, 1, input.as_ptr(), 5, &mut out) };
+        assert_eq!(AocStatus::NullPointer, status);
+    }
+
+    #[test]
+    fn every_example_solves() {
+        for s in aoc::SOLVERS {
+            for example in s.examples() {
+                let input = example.input.as_bytes();
+                let status = unsafe {
+                    aoc_parse(s.day(), input.as_ptr(), input.len(), std::ptr::null_mut())
+                };
+                assert_eq!(AocStatus::Ok, status, "day {}", s.day());
+                for part in Part::ALL {
+                    let Some(expected) = example.answer(part) else {
+                        continue;
+                    };
+                    let (status, answer, _) = solve(s.day(), part as u8, input, 512);
+                    assert_eq!((AocStatus::Ok, expected), (status, answer.as_str()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn header_is_up_to_date() {
+        let generated = include_str!(concat!(env!("OUT_DIR"), "/aoc_ffi.h"));
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/include/aoc_ffi.h");
+        if std::env::var_os("UPDATE_HEADER").is_some() {
+            std::fs::write(path, generated).unwrap();
+        }
+        let checked_in = std::fs::read_to_string(path).unwrap();
+        assert!(
+            generated == checked_in,
+            "{} is out of date, rerun with UPDATE_HEADER=1",
+            path
+        );
+    }
+
+    #[test]
+    fn aoc_version_works() {
+        let version = unsafe { CStr::from_ptr(aoc_version()) };
+        assert_eq!(env!("CARGO_PKG_VERSION"), version.to_str().unwrap());
+        assert_eq!(AOC_ABI_VERSION, aoc_abi_version());
+        let message = unsafe { CStr::from_ptr(aoc_status_message(AocStatus::NoSuchDay as i32)) };
+        assert_eq!(c"no such day", message);
+    }
+}
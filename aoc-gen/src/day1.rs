@@ -0,0 +1,14 @@
+use crate::GenRng;
+use rand::Rng;
+
+pub fn generate(rng: &mut GenRng, num_elves: usize) -> String {
+    let elves: Vec<String> = (0..num_elves)
+        .map(|_| {
+            let num_items = rng.random_range(1..=12);
+            (0..num_items)
+                .map(|_| format!("{}\n", rng.random_range(1000..=60000)))
+                .collect()
+        })
+        .collect();
+    elves.join("\n")
+}
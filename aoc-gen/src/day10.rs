@@ -0,0 +1,17 @@
+use crate::GenRng;
+use rand::Rng;
+
+pub fn generate(rng: &mut GenRng, num_instructions: usize) -> String {
+    // steer x back towards the screen so the CRT draws something
+    let mut x: i32 = 1;
+    (0..num_instructions)
+        .map(|_| match rng.random_bool(0.3) {
+            true => "noop\n".to_string(),
+            false => {
+                let n = rng.random_range(-20..=20) - (x - 20) / 4;
+                x += n;
+                format!("addx {}\n", n)
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,107 @@
+use crate::GenRng;
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+enum Op {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+struct Monkey {
+    items: Vec<u64>,
+    op: Op,
+    div: u64,
+    if_true: usize,
+    if_false: usize,
+}
+
+/// Monkeys with distinct prime divisors, redrawn until the 20 rounds of
+/// part 1 fit in a `u64`.
+pub fn generate(rng: &mut GenRng, num_monkeys: usize) -> String {
+    let num_monkeys = num_monkeys.min(PRIMES.len());
+    loop {
+        let monkeys = random_monkeys(rng, num_monkeys);
+        if part1_fits(&monkeys) {
+            return format_monkeys(&monkeys);
+        }
+    }
+}
+
+fn random_monkeys(rng: &mut GenRng, num_monkeys: usize) -> Vec<Monkey> {
+    let mut primes = PRIMES;
+    primes.shuffle(rng);
+    let square = rng.random_range(0..num_monkeys);
+    let other = |rng: &mut GenRng, id: usize| (id + rng.random_range(1..num_monkeys)) % num_monkeys;
+    (0..num_monkeys)
+        .map(|id| {
+            let num_items = rng.random_range(1..=6);
+            let op = match (id == square, rng.random_bool(0.5)) {
+                (true, _) => Op::Square,
+                (false, true) => Op::Add(rng.random_range(1..=8)),
+                (false, false) => Op::Multiply(rng.random_range(2..=19)),
+            };
+            Monkey {
+                items: (0..num_items).map(|_| rng.random_range(50..=99)).collect(),
+                op,
+                div: primes[id],
+                if_true: other(rng, id),
+                if_false: other(rng, id),
+            }
+        })
+        .collect()
+}
+
+fn part1_fits(monkeys: &[Monkey]) -> bool {
+    let mut items: Vec<Vec<u64>> = monkeys.iter().map(|m| m.items.clone()).collect();
+    for _ in 0..20 {
+        for (id, m) in monkeys.iter().enumerate() {
+            for old in std::mem::take(&mut items[id]) {
+                let new = match m.op {
+                    Op::Add(k) => old.checked_add(k),
+                    Op::Multiply(k) => old.checked_mul(k),
+                    Op::Square => old.checked_mul(old),
+                };
+                let Some(new) = new else {
+                    return false;
+                };
+                let new = new / 3;
+                let to = match new.is_multiple_of(m.div) {
+                    true => m.if_true,
+                    false => m.if_false,
+                };
+                items[to].push(new);
+            }
+        }
+    }
+    true
+}
+
+fn format_monkeys(monkeys: &[Monkey]) -> String {
+    let blocks: Vec<String> = monkeys
+        .iter()
+        .enumerate()
+        .map(|(id, m)| {
+            let items: Vec<String> = m.items.iter().map(|i| i.to_string()).collect();
+            let op = match m.op {
+                Op::Add(k) => format!("old + {}", k),
+                Op::Multiply(k) => format!("old * {}", k),
+                Op::Square => "old * old".to_string(),
+            };
+            format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  \
+                 Test: divisible by {}\n    If true: throw to monkey {}\n    \
+                 If false: throw to monkey {}\n",
+                id,
+                items.join(", "),
+                op,
+                m.div,
+                m.if_true,
+                m.if_false
+            )
+        })
+        .collect();
+    blocks.join("\n")
+}
@@ -0,0 +1,33 @@
+use crate::GenRng;
+use rand::Rng;
+
+/// Random terrain around a path from `S` to `E` that climbs one letter at
+/// most per step, so `E` is always reachable.
+pub fn generate(rng: &mut GenRng, width: usize) -> String {
+    let height = (width / 4).max(5);
+    let mut grid: Vec<Vec<u8>> = (0..height)
+        .map(|_| (0..width).map(|_| b'a' + rng.random_range(0..26)).collect())
+        .collect();
+
+    let start = (rng.random_range(0..height), 0);
+    let end = (rng.random_range(0..height), rng.random_range(25..width));
+    // down or up the first column, then along the end's row
+    let mut path: Vec<(usize, usize)> = Vec::new();
+    let mut row = start.0;
+    while row != end.0 {
+        path.push((row, 0));
+        row = if row < end.0 { row + 1 } else { row - 1 };
+    }
+    path.extend((0..=end.1).map(|col| (end.0, col)));
+
+    let len = path.len() - 1;
+    for (step, &(row, col)) in path.iter().enumerate() {
+        grid[row][col] = b'a' + (25 * step / len) as u8;
+    }
+    grid[start.0][start.1] = b'S';
+    grid[end.0][end.1] = b'E';
+
+    grid.iter()
+        .map(|row| format!("{}\n", String::from_utf8_lossy(row)))
+        .collect()
+}
@@ -0,0 +1,12 @@
+use crate::GenRng;
+use rand::Rng;
+
+pub fn generate(rng: &mut GenRng, num_rounds: usize) -> String {
+    (0..num_rounds)
+        .map(|_| {
+            let theirs = ['A', 'B', 'C'][rng.random_range(0..3)];
+            let ours = ['X', 'Y', 'Z'][rng.random_range(0..3)];
+            format!("{} {}\n", theirs, ours)
+        })
+        .collect()
+}
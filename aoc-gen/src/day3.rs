@@ -0,0 +1,46 @@
+use crate::GenRng;
+use rand::Rng;
+use rand::seq::{IndexedRandom, SliceRandom};
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Each rucksack shares exactly one item between its compartments, and each
+/// group of three shares exactly one badge.
+pub fn generate(rng: &mut GenRng, num_groups: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..num_groups {
+        let badge = *ITEMS.choose(rng).unwrap();
+        // leave every other item out of one of the three rucksacks
+        let left_out: Vec<usize> = ITEMS.iter().map(|_| rng.random_range(0..3)).collect();
+        for elf in 0..3 {
+            let mut pool: Vec<u8> = ITEMS
+                .iter()
+                .zip(&left_out)
+                .filter(|&(&item, &out)| item != badge && out != elf)
+                .map(|(&item, _)| item)
+                .collect();
+            pool.shuffle(rng);
+            let shared = pool.pop().unwrap();
+            let (left_pool, right_pool) = pool.split_at(pool.len() / 2);
+
+            let half_len = rng.random_range(4..=16);
+            let mut left = vec![shared];
+            let mut right = vec![shared];
+            match rng.random_bool(0.5) {
+                true => left.push(badge),
+                false => right.push(badge),
+            }
+            while left.len() < half_len || left.len() < right.len() {
+                left.push(*left_pool.choose(rng).unwrap());
+            }
+            while right.len() < left.len() {
+                right.push(*right_pool.choose(rng).unwrap());
+            }
+            left.shuffle(rng);
+            right.shuffle(rng);
+            s.extend(left.iter().chain(&right).map(|&b| char::from(b)));
+            s.push('\n');
+        }
+    }
+    s
+}
@@ -0,0 +1,16 @@
+use crate::GenRng;
+use rand::Rng;
+
+pub fn generate(rng: &mut GenRng, num_pairs: usize) -> String {
+    let range = |rng: &mut GenRng| {
+        let start = rng.random_range(1..=99);
+        (start, rng.random_range(start..=99))
+    };
+    (0..num_pairs)
+        .map(|_| {
+            let (a, b) = range(rng);
+            let (c, d) = range(rng);
+            format!("{}-{},{}-{}\n", a, b, c, d)
+        })
+        .collect()
+}
@@ -0,0 +1,49 @@
+use crate::GenRng;
+use rand::Rng;
+
+/// Crate stacks followed by moves that never take more crates than a
+/// stack holds.
+pub fn generate(rng: &mut GenRng, num_moves: usize) -> String {
+    // the solution finds the stack numbers line by its " 1   2"
+    let num_stacks = rng.random_range(2..=9);
+    let mut stacks: Vec<Vec<char>> = (0..num_stacks)
+        .map(|_| {
+            let height = rng.random_range(0..=8);
+            (0..height)
+                .map(|_| char::from(b'A' + rng.random_range(0..26)))
+                .collect()
+        })
+        .collect();
+    // one stack reaches the top line
+    let tallest = rng.random_range(0..num_stacks);
+    stacks[tallest].push(char::from(b'A' + rng.random_range(0..26)));
+    let max_height = stacks.iter().map(|v| v.len()).max().unwrap();
+
+    let mut s = String::new();
+    for level in (0..max_height).rev() {
+        let slots: Vec<String> = stacks
+            .iter()
+            .map(|v| match v.get(level) {
+                Some(c) => format!("[{}]", c),
+                None => "   ".to_string(),
+            })
+            .collect();
+        s.push_str(&slots.join(" "));
+        s.push('\n');
+    }
+    let numbers: Vec<String> = (1..=num_stacks).map(|n| format!(" {} ", n)).collect();
+    s.push_str(&numbers.join(" "));
+    s.push_str("\n\n");
+
+    for _ in 0..num_moves {
+        let non_empty: Vec<usize> = (0..num_stacks).filter(|&i| !stacks[i].is_empty()).collect();
+        let from = non_empty[rng.random_range(0..non_empty.len())];
+        let to = (from + rng.random_range(1..num_stacks)) % num_stacks;
+        let from_len = stacks[from].len();
+        let qty = rng.random_range(1..=from_len);
+        let moved = stacks[from].split_off(from_len - qty);
+        stacks[to].extend(moved);
+        s.push_str(&format!("move {} from {} to {}\n", qty, from + 1, to + 1));
+    }
+    s
+}
@@ -0,0 +1,18 @@
+use crate::GenRng;
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+/// Repeats from a few letters, so neither marker turns up before the
+/// 14 distinct letters placed after `len` characters.
+pub fn generate(rng: &mut GenRng, len: usize) -> String {
+    let mut s: String = (0..len)
+        .map(|_| char::from(b'a' + rng.random_range(0..3)))
+        .collect();
+    let mut message: Vec<u8> = (b'a'..=b'z').collect();
+    message.shuffle(rng);
+    s.extend(message[..14].iter().map(|&b| char::from(b)));
+    let tail_len = rng.random_range(0..=len);
+    s.extend((0..tail_len).map(|_| char::from(b'a' + rng.random_range(0..26))));
+    s.push('\n');
+    s
+}
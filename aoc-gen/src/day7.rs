@@ -0,0 +1,97 @@
+use crate::GenRng;
+use rand::Rng;
+use std::collections::HashSet;
+
+/// Part 2 needs between 40,000,000 and 70,000,000 of the disk in use.
+const MIN_USED: u64 = 40_000_001;
+const MAX_USED: u64 = 69_999_999;
+
+struct Dir {
+    name: String,
+    files: Vec<(u64, String)>,
+    children: Vec<usize>,
+}
+
+/// A terminal session that lists every directory once, depth first.
+pub fn generate(rng: &mut GenRng, num_dirs: usize) -> String {
+    let mut dirs = vec![Dir {
+        name: "/".to_string(),
+        files: Vec::new(),
+        children: Vec::new(),
+    }];
+    for idx in 1..num_dirs {
+        let parent = rng.random_range(0..idx);
+        dirs.push(Dir {
+            name: unique_name(rng, &dirs, parent, false),
+            files: Vec::new(),
+            children: Vec::new(),
+        });
+        dirs[parent].children.push(idx);
+    }
+
+    let target = rng.random_range(MIN_USED..=MAX_USED);
+    let mut used = 0;
+    for idx in 0..num_dirs {
+        for _ in 0..rng.random_range(0..=4) {
+            let size = rng.random_range(1..=300_000).min(target - used);
+            if size == 0 {
+                break;
+            }
+            let name = unique_name(rng, &dirs, idx, true);
+            dirs[idx].files.push((size, name));
+            used += size;
+        }
+    }
+    while used < target {
+        let idx = rng.random_range(0..num_dirs);
+        let size = rng.random_range(1_000_000..=10_000_000).min(target - used);
+        let name = unique_name(rng, &dirs, idx, true);
+        dirs[idx].files.push((size, name));
+        used += size;
+    }
+
+    let mut s = String::new();
+    write_session(&dirs, 0, &mut s);
+    s
+}
+
+fn write_session(dirs: &[Dir], idx: usize, s: &mut String) {
+    let dir = &dirs[idx];
+    s.push_str(&format!("$ cd {}\n$ ls\n", dir.name));
+    for &child in &dir.children {
+        s.push_str(&format!("dir {}\n", dirs[child].name));
+    }
+    for (size, name) in &dir.files {
+        s.push_str(&format!("{} {}\n", size, name));
+    }
+    for &child in &dir.children {
+        write_session(dirs, child, s);
+        s.push_str("$ cd ..\n");
+    }
+}
+
+fn unique_name(rng: &mut GenRng, dirs: &[Dir], parent: usize, is_file: bool) -> String {
+    let dir = &dirs[parent];
+    let taken: HashSet<&str> = dir
+        .children
+        .iter()
+        .map(|&c| dirs[c].name.as_str())
+        .chain(dir.files.iter().map(|f| f.1.as_str()))
+        .collect();
+    loop {
+        let mut name = random_word(rng);
+        if is_file && rng.random_bool(0.5) {
+            name = format!("{}.{}", name, random_word(rng));
+        }
+        if !taken.contains(name.as_str()) {
+            return name;
+        }
+    }
+}
+
+fn random_word(rng: &mut GenRng) -> String {
+    let len = rng.random_range(1..=8);
+    (0..len)
+        .map(|_| char::from(b'a' + rng.random_range(0..26)))
+        .collect()
+}
@@ -0,0 +1,14 @@
+use crate::GenRng;
+use rand::Rng;
+
+pub fn generate(rng: &mut GenRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut row: String = (0..size)
+                .map(|_| char::from(b'0' + rng.random_range(0..=9)))
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
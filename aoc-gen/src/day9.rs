@@ -0,0 +1,11 @@
+use crate::GenRng;
+use rand::Rng;
+
+pub fn generate(rng: &mut GenRng, num_moves: usize) -> String {
+    (0..num_moves)
+        .map(|_| {
+            let dir = ['U', 'D', 'L', 'R'][rng.random_range(0..4)];
+            format!("{} {}\n", dir, rng.random_range(1..=19))
+        })
+        .collect()
+}
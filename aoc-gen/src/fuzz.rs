@@ -0,0 +1,133 @@
+//! Broken inputs, for checking that parsers fail with an error rather than
+//! a panic.
+
+use crate::GenRng;
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+/// Fragments that are likely to reach past the first check of a parser.
+const TOKENS: &[&str] = &[
+    "\n",
+    "\n\n",
+    " ",
+    "  ",
+    "0",
+    "1",
+    "9",
+    "-1",
+    "-",
+    ",",
+    ":",
+    "$",
+    "[",
+    "]",
+    "[A]",
+    "   ",
+    "dir ",
+    "$ cd ",
+    "$ cd ..",
+    "$ ls",
+    "..",
+    "/",
+    "addx ",
+    "noop",
+    "move ",
+    " from ",
+    " to ",
+    "old",
+    "*",
+    "+",
+    "Monkey ",
+    "S",
+    "E",
+    "A",
+    "X",
+    "z",
+    "é",
+    "\t",
+    "\r\n",
+    "18446744073709551616",
+    "4294967296",
+    "99999999999999999999999",
+];
+
+/// Applies a few random edits to a valid `input`, so the result is usually
+/// almost, but not quite, valid.
+pub fn mutate(rng: &mut GenRng, input: &str) -> String {
+    let mut s: Vec<char> = input.chars().collect();
+    for _ in 0..rng.random_range(1..=3) {
+        let at = rng.random_range(0..=s.len());
+        match rng.random_range(0..6) {
+            0 if at < s.len() => {
+                let len = rng.random_range(1..=8).min(s.len() - at);
+                s.drain(at..at + len);
+            }
+            1 => {
+                let token = TOKENS.choose(rng).unwrap();
+                s.splice(at..at, token.chars());
+            }
+            2 if at < s.len() => s[at] = random_char(rng),
+            3 => s.truncate(at),
+            4 => {
+                let end = (at + rng.random_range(1..=40)).min(s.len());
+                let copy: Vec<char> = s[at..end].to_vec();
+                let to = rng.random_range(0..=s.len());
+                s.splice(to..to, copy);
+            }
+            _ => {
+                let lines: Vec<String> = s
+                    .iter()
+                    .collect::<String>()
+                    .lines()
+                    .map(String::from)
+                    .collect();
+                let drop = rng.random_range(0..lines.len().max(1));
+                s = lines
+                    .iter()
+                    .enumerate()
+                    .filter(|&(idx, _)| idx != drop)
+                    .map(|(_, l)| format!("{}\n", l))
+                    .collect::<String>()
+                    .chars()
+                    .collect();
+            }
+        }
+    }
+    s.into_iter().collect()
+}
+
+/// Up to `max_len` random tokens and characters.
+pub fn arbitrary(rng: &mut GenRng, max_len: usize) -> String {
+    let len = rng.random_range(0..=max_len);
+    (0..len)
+        .map(|_| match rng.random_bool(0.5) {
+            true => TOKENS.choose(rng).unwrap().to_string(),
+            false => random_char(rng).to_string(),
+        })
+        .collect()
+}
+
+fn random_char(rng: &mut GenRng) -> char {
+    match rng.random_range(0..4) {
+        0 => char::from(rng.random_range(b' '..=b'~')),
+        1 => char::from(rng.random_range(b'0'..=b'9')),
+        2 => *['\n', ' ', '\0', 'ß', '→', '🦀'].choose(rng).unwrap(),
+        _ => char::from(rng.random_range(b'a'..=b'z')),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn mutate_changes_input() {
+        let input = crate::generate(9, 20, 1).unwrap();
+        let mut rng = GenRng::seed_from_u64(1);
+        let changed = (0..20)
+            .filter(|_| mutate(&mut rng, &input) != input)
+            .count();
+        assert!(changed > 15);
+    }
+}
@@ -0,0 +1,93 @@
+//! Random but valid puzzle inputs, for stress testing the solutions.
+//!
+//! The same day, size and seed always give the same input. [`fuzz`] breaks
+//! them again, for testing that parsers reject bad input cleanly.
+
+mod day1;
+mod day10;
+mod day11;
+mod day12;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+pub mod fuzz;
+
+use rand::SeedableRng;
+use thiserror::Error;
+
+pub type GenRng = rand_chacha::ChaCha8Rng;
+
+#[derive(Error, Debug)]
+pub enum GenError {
+    #[error("No input generator for day {0}")]
+    NoGenerator(u8),
+}
+
+/// What `size` counts for each day, and the smallest size that's used.
+pub const SIZES: [(&str, usize); 12] = [
+    ("elves", 3),
+    ("rounds", 1),
+    ("groups of three rucksacks", 1),
+    ("section assignment pairs", 1),
+    ("moves", 1),
+    ("characters before the message", 14),
+    ("directories", 1),
+    ("rows and columns of trees", 1),
+    ("moves", 1),
+    ("instructions", 1),
+    ("monkeys, at most 9", 2),
+    ("columns of the heightmap", 26),
+];
+
+/// Generates an input for `day`, sized by `size` as described in [`SIZES`].
+pub fn generate(day: u8, size: usize, seed: u64) -> Result<String, GenError> {
+    let (_, min_size) = SIZES
+        .get((day as usize).wrapping_sub(1))
+        .ok_or(GenError::NoGenerator(day))?;
+    let size = size.max(*min_size);
+    let mut rng = GenRng::seed_from_u64(seed);
+    let input = match day {
+        1 => day1::generate(&mut rng, size),
+        2 => day2::generate(&mut rng, size),
+        3 => day3::generate(&mut rng, size),
+        4 => day4::generate(&mut rng, size),
+        5 => day5::generate(&mut rng, size),
+        6 => day6::generate(&mut rng, size),
+        7 => day7::generate(&mut rng, size),
+        8 => day8::generate(&mut rng, size),
+        9 => day9::generate(&mut rng, size),
+        10 => day10::generate(&mut rng, size),
+        11 => day11::generate(&mut rng, size),
+        12 => day12::generate(&mut rng, size),
+        _ => return Err(GenError::NoGenerator(day)),
+    };
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_is_reproducible() {
+        for day in 1..=12 {
+            let a = generate(day, 20, 7).unwrap();
+            assert_eq!(a, generate(day, 20, 7).unwrap(), "day {}", day);
+            assert_ne!(a, generate(day, 20, 8).unwrap(), "day {}", day);
+        }
+    }
+
+    #[test]
+    fn generate_unknown_day_fails() {
+        assert!(matches!(generate(0, 10, 1), Err(GenError::NoGenerator(0))));
+        assert!(matches!(
+            generate(13, 10, 1),
+            Err(GenError::NoGenerator(13))
+        ));
+    }
+}
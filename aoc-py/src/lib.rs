@@ -0,0 +1,294 @@
+//! The solutions and their simulations as a Python module.
+//!
+//! ```python
+//! import aoc2022
+//! answer = aoc2022.solve(7, 1, open("input.txt").read())
+//!
+//! bfs = aoc2022.Bfs(open("day12.txt").read())
+//! while bfs.step():
+//!     print(bfs.steps, len(bfs.frontier()))
+//! ```
+
+use aoc_core::{BoxedError, Part, Solution};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+fn value_error(e: impl std::fmt::Display) -> PyErr {
+    PyValueError::new_err(e.to_string())
+}
+
+fn find_solver(day: u8) -> Result<&'static dyn aoc_core::Solver, BoxedError> {
+    aoc::solver(day).ok_or_else(|| format!("No solution for day {}", day).into())
+}
+
+/// Solves one part of a day, returning the answer as text. Raises
+/// `ValueError` if the day or part doesn't exist or the input can't be solved.
+#[pyfunction]
+fn solve(day: u8, part: u8, input: &str) -> PyResult<String> {
+    let solve = || find_solver(day)?.solve(Part::try_from(part)?, input);
+    solve().map_err(value_error)
+}
+
+/// Checks that `input` parses for `day`, raising `ValueError` if not.
+#[pyfunction]
+fn parse(day: u8, input: &str) -> PyResult<()> {
+    let parse = || find_solver(day)?.parse_only(input);
+    parse().map_err(value_error)
+}
+
+/// Title of a day's puzzle, `None` for days without a solution.
+#[pyfunction]
+fn title(day: u8) -> Option<&'static str> {
+    aoc::solver(day).map(|s| s.title())
+}
+
+/// Days that have a solution, in order.
+#[pyfunction]
+fn days() -> Vec<u32> {
+    // pyo3 turns a `Vec<u8>` into bytes rather than a list
+    aoc::SOLVERS.iter().map(|s| s.day().into()).collect()
+}
+
+/// Day 12's breadth first search over a heightmap, a step at a time.
+/// Coordinates are `(row, col)`.
+#[pyclass(module = "aoc2022")]
+struct Bfs {
+    grid: day12::Grid,
+    bfs: day12::Bfs,
+    from_any_ground: bool,
+}
+
+#[pymethods]
+impl Bfs {
+    /// Searches from the start, or from every square at elevation `a` for
+    /// part 2's `from_any_ground`.
+    #[new]
+    #[pyo3(signature = (input, from_any_ground = false))]
+    fn new(input: &str, from_any_ground: bool) -> PyResult<Self> {
+        let grid = day12::Day12::parse(input).map_err(value_error)?;
+        grid.get_end_coord()
+            .ok_or(day12::MyError::NoEndCell)
+            .map_err(value_error)?;
+        if !from_any_ground {
+            grid.get_start_coord()
+                .ok_or(day12::MyError::NoStartCell)
+                .map_err(value_error)?;
+        }
+        Ok(Bfs {
+            grid,
+            bfs: day12::Bfs::new(),
+            from_any_ground,
+        })
+    }
+
+    /// Takes a step, returning false once the end is reached or there's
+    /// nowhere left to go.
+    fn step(&mut self) -> bool {
+        let stuck = self.bfs.current.is_empty() && self.bfs.num_steps > 0;
+        if self.reached_end() || stuck {
+            return false;
+        }
+        match self.from_any_ground {
+            true => self.bfs.step_up(&self.grid),
+            false => self.bfs.step(&self.grid),
+        }
+        true
+    }
+
+    fn reset(&mut self) {
+        self.bfs.reset();
+    }
+
+    #[getter]
+    fn steps(&self) -> usize {
+        self.bfs.num_steps
+    }
+
+    #[getter]
+    fn width(&self) -> usize {
+        self.grid.width
+    }
+
+    #[getter]
+    fn height(&self) -> usize {
+        self.grid.height
+    }
+
+    #[getter]
+    fn reached_end(&self) -> bool {
+        self.grid
+            .get_end_coord()
+            .is_some_and(|end| self.bfs.current.contains(&end))
+    }
+
+    /// Squares reached on the last step, sorted.
+    fn frontier(&self) -> Vec<(usize, usize)> {
+        let mut frontier = self.bfs.current.iter().copied().collect::<Vec<_>>();
+        frontier.sort();
+        frontier
+    }
+
+    /// Every square reached so far, mapped to the one it was reached from.
+    fn visited(&self) -> std::collections::HashMap<(usize, usize), Option<(usize, usize)>> {
+        self.bfs.visited.clone()
+    }
+
+    /// The way back from `coord` to where the search started.
+    fn path_from(&self, coord: (usize, usize)) -> PyResult<Vec<(usize, usize)>> {
+        self.bfs.trace_back_path(coord).map_err(value_error)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Bfs({}x{}, steps={}, visited={})",
+            self.grid.width,
+            self.grid.height,
+            self.bfs.num_steps,
+            self.bfs.visited.len()
+        )
+    }
+}
+
+/// Day 11's monkeys passing items around, a round at a time.
+#[pyclass(module = "aoc2022")]
+struct MonkeyList(day11::MonkeyList);
+
+#[pymethods]
+impl MonkeyList {
+    #[new]
+    fn new(input: &str) -> PyResult<Self> {
+        day11::Day11::parse(input)
+            .map(MonkeyList)
+            .map_err(value_error)
+    }
+
+    /// Plays a round, dividing worry levels by 3 after each inspection when
+    /// `gets_bored` as in part 1. Returns the number of rounds played.
+    #[pyo3(signature = (gets_bored = true))]
+    fn complete_round(&mut self, gets_bored: bool) -> PyResult<usize> {
+        self.0.complete_round_with(gets_bored).map_err(value_error)
+    }
+
+    #[getter]
+    fn round(&self) -> usize {
+        self.0.round
+    }
+
+    /// Worry levels of the items each monkey holds.
+    fn items(&self) -> Vec<Vec<u64>> {
+        self.0
+            .data
+            .iter()
+            .map(|m| m.items.iter().map(|item| item.0).collect())
+            .collect()
+    }
+
+    /// How many items each monkey has inspected.
+    fn inspections(&self) -> Vec<u64> {
+        self.0.inspections()
+    }
+
+    fn monkey_business(&self) -> PyResult<u64> {
+        self.0.monkey_business().map_err(value_error)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "MonkeyList(monkeys={}, round={})",
+            self.0.data.len(),
+            self.0.round
+        )
+    }
+}
+
+/// Day 10's CPU drawing on the CRT, a clock cycle at a time.
+#[pyclass(module = "aoc2022")]
+struct Cpu(day10::Cpu);
+
+#[pymethods]
+impl Cpu {
+    #[new]
+    fn new(input: &str) -> PyResult<Self> {
+        let commands = day10::Day10::parse(input).map_err(value_error)?;
+        Ok(Cpu(day10::Cpu::new(commands)))
+    }
+
+    /// Runs one cycle, returning false once there are no commands left.
+    /// Raises `ValueError` if the x register overflows.
+    fn tick(&mut self) -> PyResult<bool> {
+        self.0.tick().map_err(value_error)
+    }
+
+    #[getter]
+    fn cycle(&self) -> i32 {
+        self.0.cycle
+    }
+
+    #[getter]
+    fn x(&self) -> i32 {
+        self.0.x
+    }
+
+    #[getter]
+    fn signal_strengths(&self) -> Vec<i32> {
+        self.0.signal_strengths.clone()
+    }
+
+    /// The CRT so far, rows of `#` and `.`.
+    fn image(&self) -> String {
+        self.0.crt.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Cpu(cycle={}, x={})", self.0.cycle, self.0.x)
+    }
+}
+
+#[pymodule]
+fn aoc2022(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(title, m)?)?;
+    m.add_function(wrap_pyfunction!(days, m)?)?;
+    m.add_class::<Bfs>()?;
+    m.add_class::<MonkeyList>()?;
+    m.add_class::<Cpu>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solve_works() {
+        assert_eq!("24000", solve(1, 1, "1000\n2000\n\n24000\n\n").unwrap());
+        assert!(solve(13, 1, "").is_err());
+        assert!(solve(1, 3, "1\n").is_err());
+        assert!(parse(1, "x\n").is_err());
+        assert_eq!(Some("Hill Climbing Algorithm"), title(12));
+        assert_eq!((1..=12).collect::<Vec<u32>>(), days());
+    }
+
+    #[test]
+    fn simulations_work() {
+        let mut bfs = Bfs::new(day12::EXAMPLE, false).unwrap();
+        while bfs.step() {}
+        assert!(bfs.reached_end());
+        assert_eq!(31, bfs.steps());
+        assert_eq!((0, 0), *bfs.path_from((2, 5)).unwrap().last().unwrap());
+        assert!(Bfs::new("abc\n", false).is_err());
+
+        let mut monkeys = MonkeyList::new(day11::EXAMPLE).unwrap();
+        for _ in 0..20 {
+            monkeys.complete_round(true).unwrap();
+        }
+        assert_eq!(vec![101, 95, 7, 105], monkeys.inspections());
+        assert_eq!(10605, monkeys.monkey_business().unwrap());
+
+        let mut cpu = Cpu::new(day10::EXAMPLE).unwrap();
+        while cpu.tick().unwrap() {}
+        assert_eq!(day10::EXAMPLE_IMAGE, cpu.image());
+    }
+}
@@ -0,0 +1,74 @@
+use aoc_core::{BoxedError, Part};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+
+/// Days that have an animation, in order.
+pub const DAYS: [u8; 4] = [5, 9, 10, 12];
+
+/// A day's solution, run one step at a time and drawn to the terminal.
+pub trait Animation {
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    /// Advances one step, returning false once there's nothing left to do.
+    fn step(&mut self) -> bool;
+    /// Goes back to the start of the input.
+    fn reset(&mut self);
+    /// Where the animation is up to, in a line.
+    fn status(&self) -> String;
+    fn render(&self, frame: &mut Frame, area: Rect);
+}
+
+/// Animates solving `part` of `input` for `day`.
+pub fn load(day: u8, input: &str, part: Part) -> Result<Box<dyn Animation>, BoxedError> {
+    Ok(match day {
+        5 => Box::new(crate::day5::Crane::new(input, part)?),
+        9 => Box::new(crate::day9::Rope::new(input, part)?),
+        10 => Box::new(crate::day10::Screen::new(input)?),
+        12 => Box::new(crate::day12::Search::new(input, part)?),
+        _ => return Err(format!("No animation for day {}", day).into()),
+    })
+}
+
+/// Animates `part` of the day's example from the puzzle text.
+pub fn example(day: u8, part: Part) -> Result<Box<dyn Animation>, BoxedError> {
+    let input = match day {
+        5 => day5::EXAMPLE,
+        // part 2's rope hardly moves the tail in the small example
+        9 if part == Part::Two => day9::LARGER_EXAMPLE,
+        9 => day9::EXAMPLE,
+        10 => day10::EXAMPLE,
+        12 => day12::EXAMPLE,
+        _ => return Err(format!("No animation for day {}", day).into()),
+    };
+    load(day, input, part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    #[test]
+    fn every_example_animates() {
+        let mut terminal = Terminal::new(TestBackend::new(60, 30)).unwrap();
+        for day in DAYS {
+            for part in Part::ALL {
+                let mut animation = example(day, part).unwrap();
+                assert_eq!(day, animation.day());
+                let mut num_steps = 0;
+                while animation.step() {
+                    num_steps += 1;
+                    terminal
+                        .draw(|frame| animation.render(frame, frame.area()))
+                        .unwrap();
+                }
+                assert!(num_steps > 0, "day {} part {} never stepped", day, part);
+                assert!(!animation.step());
+
+                animation.reset();
+                assert!(animation.step());
+            }
+        }
+        assert!(example(1, Part::One).is_err());
+    }
+}
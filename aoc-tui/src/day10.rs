@@ -0,0 +1,118 @@
+use crate::Animation;
+use aoc_core::Solution;
+use day10::{CRT_HEIGHT, CRT_WIDTH, Cpu, Day10};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+/// The CRT being drawn, a clock cycle per step.
+pub struct Screen {
+    start: Cpu,
+    cpu: Cpu,
+    error: Option<day10::MyError>,
+}
+
+impl Screen {
+    pub fn new(input: &str) -> Result<Self, day10::MyError> {
+        let cpu = Cpu::new(Day10::parse(input)?);
+        Ok(Screen {
+            start: cpu.clone(),
+            cpu,
+            error: None,
+        })
+    }
+}
+
+impl Animation for Screen {
+    fn day(&self) -> u8 {
+        Day10::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        Day10::TITLE
+    }
+
+    fn step(&mut self) -> bool {
+        match self.cpu.tick() {
+            Ok(ticked) => ticked,
+            Err(e) => {
+                self.error = Some(e);
+                false
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        self.cpu = self.start.clone();
+        self.error = None;
+    }
+
+    fn status(&self) -> String {
+        if let Some(e) = &self.error {
+            return format!("cycle {}, {}", self.cpu.cycle, e);
+        }
+        format!(
+            "cycle {}, x = {}, signal strength sum {}",
+            self.cpu.cycle - 1,
+            self.cpu.x,
+            self.cpu.signal_strengths.iter().sum::<i32>()
+        )
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let drawn = (self.cpu.cycle - 2).max(0) as usize;
+        let sprite = Style::new().fg(Color::Yellow);
+
+        let mut lines = self
+            .cpu
+            .crt
+            .0
+            .chunks(CRT_WIDTH)
+            .enumerate()
+            .map(|(row, pixels)| {
+                let spans = pixels.iter().enumerate().map(|(col, &lit)| {
+                    let pixel = if lit { "#" } else { "." };
+                    match row * CRT_WIDTH + col == drawn && self.cpu.cycle > 1 {
+                        true => {
+                            Span::styled(pixel, Style::new().fg(Color::Black).bg(Color::Yellow))
+                        }
+                        false => Span::raw(pixel),
+                    }
+                });
+                Line::from(spans.collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
+
+        // where the sprite is on the row being drawn
+        let sprite_cols = (self.cpu.x - 1)..=(self.cpu.x + 1);
+        let sprite_line = (0..CRT_WIDTH as i32)
+            .map(|col| match sprite_cols.contains(&col) {
+                true => Span::styled("^", sprite),
+                false => Span::raw(" "),
+            })
+            .collect::<Vec<_>>();
+        lines.insert(
+            CRT_HEIGHT.min(drawn / CRT_WIDTH + 1),
+            Line::from(sprite_line),
+        );
+        frame.render_widget(Paragraph::new(lines), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn screen_works() {
+        let mut screen = Screen::new(day10::EXAMPLE).unwrap();
+        while screen.step() {}
+        assert_eq!(day10::EXAMPLE_IMAGE, screen.cpu.crt.to_string());
+        assert_eq!(
+            "cycle 240, x = 17, signal strength sum 13140",
+            screen.status()
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use crate::Animation;
+use aoc_core::{Part, Solution};
+use day12::{Bfs, Cell, Day12, Grid};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+use std::collections::HashSet;
+
+/// The breadth first search spreading over the heightmap, a step per step.
+pub struct Search {
+    grid: Grid,
+    bfs: Bfs,
+    /// Searching from every square at elevation `a` rather than the start.
+    from_any_ground: bool,
+}
+
+impl Search {
+    pub fn new(input: &str, part: Part) -> Result<Self, day12::MyError> {
+        let grid = Day12::parse(input)?;
+        grid.get_end_coord().ok_or(day12::MyError::NoEndCell)?;
+        grid.get_start_coord().ok_or(day12::MyError::NoStartCell)?;
+        Ok(Search {
+            grid,
+            bfs: Bfs::new(),
+            from_any_ground: part == Part::Two,
+        })
+    }
+
+    fn found_end(&self) -> bool {
+        self.grid
+            .get_end_coord()
+            .is_some_and(|end| self.bfs.current.contains(&end))
+    }
+
+    fn path(&self) -> HashSet<(usize, usize)> {
+        match self.grid.get_end_coord() {
+            Some(end) if self.found_end() => self
+                .bfs
+                .trace_back_path(end)
+                .unwrap_or_default()
+                .into_iter()
+                .collect(),
+            _ => HashSet::new(),
+        }
+    }
+}
+
+impl Animation for Search {
+    fn day(&self) -> u8 {
+        Day12::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        Day12::TITLE
+    }
+
+    fn step(&mut self) -> bool {
+        let stuck = self.bfs.current.is_empty() && self.bfs.num_steps > 0;
+        if self.found_end() || stuck {
+            return false;
+        }
+        match self.from_any_ground {
+            true => self.bfs.step_up(&self.grid),
+            false => self.bfs.step(&self.grid),
+        }
+        true
+    }
+
+    fn reset(&mut self) {
+        self.bfs.reset();
+    }
+
+    fn status(&self) -> String {
+        let outcome = match (self.found_end(), self.bfs.current.is_empty()) {
+            (true, _) => ", reached the end",
+            (false, true) if self.bfs.num_steps > 0 => ", no path to the end",
+            _ => "",
+        };
+        format!(
+            "step {}, {} squares visited, {} on the frontier{}",
+            self.bfs.num_steps,
+            self.bfs.visited.len(),
+            self.bfs.current.len(),
+            outcome
+        )
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let path = self.path();
+        let lines = self
+            .grid
+            .data
+            .chunks(self.grid.width)
+            .enumerate()
+            .map(|(row, cells)| {
+                let spans = cells.iter().enumerate().map(|(col, cell)| {
+                    let coord = (row, col);
+                    let style = if path.contains(&coord) {
+                        Style::new().fg(Color::Black).bg(Color::Red)
+                    } else if self.bfs.current.contains(&coord) {
+                        Style::new().fg(Color::Black).bg(Color::Yellow)
+                    } else if self.bfs.visited.contains_key(&coord) {
+                        Style::new().fg(Color::Cyan)
+                    } else {
+                        Style::new().fg(Color::DarkGray)
+                    };
+                    let style = match cell {
+                        Cell::Start | Cell::End => style.add_modifier(Modifier::BOLD),
+                        Cell::Square(_) => style,
+                    };
+                    Span::styled(format!("{:?}", cell), style)
+                });
+                Line::from(spans.collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
+        frame.render_widget(Paragraph::new(lines), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_works() {
+        for (part, num_steps) in [(Part::One, 31), (Part::Two, 29)] {
+            let mut search = Search::new(day12::EXAMPLE, part).unwrap();
+            while search.step() {}
+            assert_eq!(num_steps, search.bfs.num_steps);
+            assert_eq!(num_steps + 1, search.path().len());
+        }
+    }
+}
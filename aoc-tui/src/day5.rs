@@ -0,0 +1,131 @@
+use crate::Animation;
+use aoc_core::{Part, Solution};
+use day5::{Crate, Day5, MoveQtyFromTo};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+/// The crane working through the rearrangement, a move per step.
+pub struct Crane {
+    part: Part,
+    start: Vec<Vec<Crate>>,
+    moves: Vec<MoveQtyFromTo>,
+    stacks: Vec<Vec<Crate>>,
+    num_moved: usize,
+}
+
+impl Crane {
+    pub fn new(input: &str, part: Part) -> Result<Self, day5::MyError> {
+        let (stacks, moves) = Day5::parse(input)?;
+        Ok(Crane {
+            part,
+            start: stacks.clone(),
+            moves,
+            stacks,
+            num_moved: 0,
+        })
+    }
+
+    fn last_move(&self) -> Option<&MoveQtyFromTo> {
+        self.num_moved.checked_sub(1).map(|idx| &self.moves[idx])
+    }
+}
+
+impl Animation for Crane {
+    fn day(&self) -> u8 {
+        Day5::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        Day5::TITLE
+    }
+
+    fn step(&mut self) -> bool {
+        let Some(m) = self.moves.get(self.num_moved) else {
+            return false;
+        };
+        match self.part {
+            Part::One => day5::exec_move_part1(&mut self.stacks, m),
+            Part::Two => day5::exec_move_part2(&mut self.stacks, m),
+        }
+        self.num_moved += 1;
+        true
+    }
+
+    fn reset(&mut self) {
+        self.stacks = self.start.clone();
+        self.num_moved = 0;
+    }
+
+    fn status(&self) -> String {
+        let tops = day5::top_of_crate_columns(self.stacks.clone());
+        match self.last_move() {
+            Some(m) => format!(
+                "move {}/{}: {} from {} to {}, tops {}",
+                self.num_moved,
+                self.moves.len(),
+                m.0,
+                m.1,
+                m.2,
+                tops
+            ),
+            None => format!("{} moves to make, tops {}", self.moves.len(), tops),
+        }
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let height = self.stacks.iter().map(|s| s.len()).max().unwrap_or(0);
+        // the crates just moved, highlighted on top of their new stack
+        let (moved_to, num_moved) = match self.last_move() {
+            Some(m) => (m.2 as usize - 1, m.0 as usize),
+            None => (usize::MAX, 0),
+        };
+        let moved = Style::new().fg(Color::Yellow);
+
+        let mut lines = (0..height)
+            .rev()
+            .map(|row| {
+                let spans =
+                    self.stacks
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, stack)| match stack.get(row) {
+                            Some(c) if idx == moved_to && row + num_moved >= stack.len() => {
+                                Span::styled(format!("[{}] ", c.0), moved)
+                            }
+                            Some(c) => Span::raw(format!("[{}] ", c.0)),
+                            None => Span::raw("    "),
+                        });
+                Line::from(spans.collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
+        let numbers = (1..=self.stacks.len())
+            .map(|n| format!(" {:<3}", n))
+            .collect::<String>();
+        lines.push(Line::from(numbers));
+
+        // keep the bottom of the stacks in view
+        let scroll = lines.len().saturating_sub(area.height as usize) as u16;
+        frame.render_widget(Paragraph::new(lines).scroll((scroll, 0)), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crane_works() {
+        for (part, tops) in [(Part::One, "CMZ"), (Part::Two, "MCD")] {
+            let mut crane = Crane::new(day5::EXAMPLE, part).unwrap();
+            while crane.step() {}
+            assert_eq!(4, crane.num_moved);
+            assert_eq!(
+                format!("move 4/4: 1 from 1 to 2, tops {}", tops),
+                crane.status()
+            );
+        }
+    }
+}
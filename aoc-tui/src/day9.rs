@@ -0,0 +1,139 @@
+use crate::Animation;
+use aoc_core::{Part, Solution};
+use day9::{Day9, Move, RopeKnot};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+use std::collections::HashSet;
+
+/// The rope following its head, a knot's length per step.
+pub struct Rope {
+    moves: Vec<Move>,
+    knots: Vec<RopeKnot>,
+    visited: HashSet<(isize, isize)>,
+    move_idx: usize,
+    /// Steps taken of the move at `move_idx`.
+    move_steps: usize,
+}
+
+impl Rope {
+    pub fn new(input: &str, part: Part) -> Result<Self, day9::MyError> {
+        let num_knots = match part {
+            Part::One => day9::NUM_ROPE_KNOTS_PART1,
+            Part::Two => day9::NUM_ROPE_KNOTS,
+        };
+        let mut rope = Rope {
+            moves: Day9::parse(input)?,
+            knots: vec![RopeKnot::default(); num_knots],
+            visited: HashSet::new(),
+            move_idx: 0,
+            move_steps: 0,
+        };
+        rope.reset();
+        Ok(rope)
+    }
+
+    fn label(&self, idx: usize) -> char {
+        match idx {
+            0 => 'H',
+            idx if idx == self.knots.len() - 1 => 'T',
+            idx => char::from_digit(idx as u32, 36).unwrap_or('+'),
+        }
+    }
+}
+
+impl Animation for Rope {
+    fn day(&self) -> u8 {
+        Day9::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        Day9::TITLE
+    }
+
+    fn step(&mut self) -> bool {
+        while self
+            .moves
+            .get(self.move_idx)
+            .is_some_and(|m| self.move_steps == m.qty)
+        {
+            self.move_idx += 1;
+            self.move_steps = 0;
+        }
+        let Some(m) = self.moves.get(self.move_idx) else {
+            return false;
+        };
+        let tail = day9::step_rope(&mut self.knots, m.dir_coords());
+        self.visited.insert(tail);
+        self.move_steps += 1;
+        true
+    }
+
+    fn reset(&mut self) {
+        self.knots.fill(RopeKnot::default());
+        self.visited = HashSet::from([RopeKnot::default().get_coords()]);
+        self.move_idx = 0;
+        self.move_steps = 0;
+    }
+
+    fn status(&self) -> String {
+        let current = match self.moves.get(self.move_idx) {
+            Some(m) => format!("{:?} {}/{}", m.dir, self.move_steps, m.qty),
+            None => "done".to_string(),
+        };
+        format!(
+            "move {}/{}: {}, tail visited {}",
+            (self.move_idx + 1).min(self.moves.len()),
+            self.moves.len(),
+            current,
+            self.visited.len()
+        )
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        // centred on the head, rows counting up the screen
+        let (head_row, head_col) = self.knots[0].get_coords();
+        let top = head_row + area.height as isize / 2;
+        let left = head_col - area.width as isize / 2;
+
+        let lines = (0..area.height as isize)
+            .map(|y| {
+                let row = top - y;
+                let spans = (0..area.width as isize).map(|x| {
+                    let coord = (row, left + x);
+                    match self.knots.iter().position(|k| k.get_coords() == coord) {
+                        Some(idx) => Span::styled(
+                            self.label(idx).to_string(),
+                            Style::new().fg(Color::Yellow),
+                        ),
+                        None if self.visited.contains(&coord) => Span::raw("#"),
+                        None if coord == (0, 0) => Span::raw("s"),
+                        None => Span::styled(".", Style::new().fg(Color::DarkGray)),
+                    }
+                });
+                Line::from(spans.collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
+        frame.render_widget(Paragraph::new(lines), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rope_works() {
+        for (input, part, num_visited) in [
+            (day9::EXAMPLE, Part::One, 13),
+            (day9::EXAMPLE, Part::Two, 1),
+            (day9::LARGER_EXAMPLE, Part::Two, 36),
+        ] {
+            let mut rope = Rope::new(input, part).unwrap();
+            while rope.step() {}
+            assert_eq!(num_visited, rope.visited.len());
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! Terminal animations of the solvers, stepped through the same state types
+//! the solvers use.
+
+pub mod animation;
+mod day10;
+mod day12;
+mod day5;
+mod day9;
+
+pub use animation::{Animation, DAYS, example, load};
@@ -0,0 +1,173 @@
+use aoc_core::{BoxedError, InputSource, Part};
+use aoc_tui::{Animation, DAYS};
+use clap::Parser;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Tabs};
+use ratatui::{DefaultTerminal, Frame};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+const FRAME_TIME: Duration = Duration::from_millis(50);
+const MAX_STEPS_PER_FRAME: usize = 1 << 12;
+
+#[derive(Parser)]
+#[command(
+    name = "aoc-tui",
+    about = "Animate solutions in the terminal",
+    after_help = "Keys: space pause, n or -> step, r restart, + and - speed, tab next day, q quit"
+)]
+struct Args {
+    /// Day to show first
+    #[arg(short, long)]
+    day: Option<u8>,
+    /// Part to animate, 1 or 2
+    #[arg(short, long, default_value = "1")]
+    part: Part,
+    /// Input to animate for --day, or - to read stdin. Defaults to the
+    /// day's example
+    #[arg(short, long, requires = "day")]
+    input: Option<InputSource>,
+}
+
+struct App {
+    animations: Vec<Box<dyn Animation>>,
+    selected: usize,
+    paused: bool,
+    steps_per_frame: usize,
+    finished: bool,
+}
+
+fn main() -> ExitCode {
+    match load(Args::parse()).and_then(|app| {
+        let mut terminal = ratatui::init();
+        let result = app.run(&mut terminal);
+        ratatui::restore();
+        result
+    }) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn load(args: Args) -> Result<App, BoxedError> {
+    let mut animations = Vec::new();
+    for day in DAYS {
+        let animation = match (&args.input, args.day) {
+            (Some(source), Some(d)) if d == day => {
+                aoc_tui::load(day, &source.read(day)?, args.part)?
+            }
+            _ => aoc_tui::example(day, args.part)?,
+        };
+        animations.push(animation);
+    }
+    let selected = match args.day {
+        Some(day) => DAYS
+            .iter()
+            .position(|&d| d == day)
+            .ok_or_else(|| format!("No animation for day {}, try one of {:?}", day, DAYS))?,
+        None => 0,
+    };
+    Ok(App {
+        animations,
+        selected,
+        paused: false,
+        steps_per_frame: 1,
+        finished: false,
+    })
+}
+
+impl App {
+    fn run(mut self, terminal: &mut DefaultTerminal) -> Result<(), BoxedError> {
+        loop {
+            let frame_start = Instant::now();
+            terminal.draw(|frame| self.draw(frame))?;
+
+            while let Some(timeout) = FRAME_TIME.checked_sub(frame_start.elapsed()) {
+                if !event::poll(timeout)? {
+                    break;
+                }
+                let Event::Key(key) = event::read()? else {
+                    continue;
+                };
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                    KeyCode::Char(' ') => self.paused = !self.paused,
+                    KeyCode::Char('n') | KeyCode::Right => {
+                        self.paused = true;
+                        self.step(1);
+                    }
+                    KeyCode::Char('r') => {
+                        self.animations[self.selected].reset();
+                        self.finished = false;
+                    }
+                    KeyCode::Char('+') | KeyCode::Char('=') => {
+                        self.steps_per_frame = (self.steps_per_frame * 2).min(MAX_STEPS_PER_FRAME);
+                    }
+                    KeyCode::Char('-') => self.steps_per_frame = (self.steps_per_frame / 2).max(1),
+                    KeyCode::Tab => self.select(self.selected + 1),
+                    KeyCode::BackTab => self.select(self.selected + DAYS.len() - 1),
+                    _ => {}
+                }
+            }
+
+            if !self.paused {
+                self.step(self.steps_per_frame);
+            }
+        }
+    }
+
+    fn select(&mut self, idx: usize) {
+        self.selected = idx % self.animations.len();
+        self.finished = false;
+    }
+
+    fn step(&mut self, num_steps: usize) {
+        let animation = &mut self.animations[self.selected];
+        for _ in 0..num_steps {
+            if !animation.step() {
+                self.finished = true;
+                break;
+            }
+        }
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [tabs_area, main_area, status_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let titles = self
+            .animations
+            .iter()
+            .map(|a| format!("{} {}", a.day(), a.title()));
+        let tabs = Tabs::new(titles)
+            .select(self.selected)
+            .highlight_style(Style::new().fg(Color::Yellow));
+        frame.render_widget(tabs, tabs_area);
+
+        let animation = &self.animations[self.selected];
+        let state = match (self.finished, self.paused) {
+            (true, _) => "finished".to_string(),
+            (false, true) => "paused".to_string(),
+            (false, false) => format!("x{}", self.steps_per_frame),
+        };
+        let block = Block::bordered().title(Line::from(format!(" {} ", state)).right_aligned());
+        let inner = block.inner(main_area);
+        frame.render_widget(block, main_area);
+        animation.render(frame, inner);
+
+        frame.render_widget(Line::from(animation.status()), status_area);
+    }
+}
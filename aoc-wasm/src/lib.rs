@@ -0,0 +1,54 @@
+//! The solutions as a wasm module for JavaScript, without the visualizer.
+//!
+//! ```js
+//! import init, { solve } from "./aoc_wasm.js";
+//! await init();
+//! const answer = solve(7, 1, inputText);
+//! ```
+
+use aoc_core::{BoxedError, Part};
+use wasm_bindgen::prelude::*;
+
+/// Solves one part of a day, returning the answer as text. Throws an
+/// `Error` with the message if the day or part doesn't exist or the input
+/// can't be solved.
+#[wasm_bindgen]
+pub fn solve(day: u8, part: u8, input: &str) -> Result<String, JsError> {
+    try_solve(day, part, input).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Title of a day's puzzle, `undefined` for days without a solution.
+#[wasm_bindgen]
+pub fn title(day: u8) -> Option<String> {
+    aoc::solver(day).map(|s| s.title().to_string())
+}
+
+/// Days that have a solution, in order.
+#[wasm_bindgen]
+pub fn days() -> Vec<u8> {
+    aoc::SOLVERS.iter().map(|s| s.day()).collect()
+}
+
+fn try_solve(day: u8, part: u8, input: &str) -> Result<String, BoxedError> {
+    let s = aoc::solver(day).ok_or_else(|| format!("No solution for day {}", day))?;
+    s.solve(Part::try_from(part)?, input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_solve_works() {
+        assert_eq!("24000", try_solve(1, 1, "1000\n2000\n\n24000\n\n").unwrap());
+        assert!(try_solve(13, 1, "").is_err());
+        assert!(try_solve(1, 3, "1\n").is_err());
+        assert!(try_solve(1, 1, "x\n").is_err());
+    }
+
+    #[test]
+    fn days_works() {
+        assert_eq!((1..=12).collect::<Vec<u8>>(), days());
+        assert_eq!(Some("Rucksack Reorganization".to_string()), title(3));
+    }
+}
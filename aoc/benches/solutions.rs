@@ -0,0 +1,112 @@
+//! Times parsing and each part of every day on its bundled input.
+//!
+//! ```sh
+//! cargo bench -p aoc -- --save-baseline before
+//! cargo bench -p aoc -- --baseline before --day 8
+//! ```
+
+use aoc::SOLVERS;
+use aoc_core::bench::{self, Baseline, NOISE_PERCENT, Stage};
+use aoc_core::{BoxedError, InputSource};
+use clap::Parser;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+#[derive(Parser)]
+struct Args {
+    /// Only benchmark this day
+    #[arg(short, long)]
+    day: Option<u8>,
+    /// Compare against a baseline saved earlier
+    #[arg(long)]
+    baseline: Option<String>,
+    /// Save the timings as a baseline with this name
+    #[arg(long)]
+    save_baseline: Option<String>,
+    /// Time spent sampling each stage, in milliseconds
+    #[arg(long, default_value_t = 500)]
+    budget_ms: u64,
+    // passed by `cargo bench`
+    #[arg(long, hide = true)]
+    bench: bool,
+}
+
+fn main() -> ExitCode {
+    match run(Args::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> Result<(), BoxedError> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let compare_to = match &args.baseline {
+        Some(name) => {
+            let path = baseline_path(&root, name);
+            let baseline = Baseline::load(&path)?
+                .ok_or_else(|| format!("No baseline saved at {}", path.display()))?;
+            Some(baseline)
+        }
+        None => None,
+    };
+    let budget = Duration::from_millis(args.budget_ms);
+
+    let mut saved = Baseline::default();
+    println!(
+        "{:>3}  {:<6} {:>12} {:>12} {:>8}",
+        "day", "stage", "median", "baseline", "change"
+    );
+    for s in SOLVERS {
+        if args.day.is_some_and(|day| day != s.day()) {
+            continue;
+        }
+        let path = root.join(format!("day{}/src/input.txt", s.day()));
+        let input = InputSource::Path(path).read(s.day())?;
+        let timings = s.measure(&input, budget)?;
+        saved.insert(s.day(), &timings);
+
+        for stage in Stage::ALL {
+            let now = timings.get(stage);
+            let before = compare_to.as_ref().and_then(|b| b.get(s.day(), stage));
+            let (before, change) = match before {
+                Some(before) => (bench::format_duration(before), describe_change(before, now)),
+                None => (String::new(), String::new()),
+            };
+            println!(
+                "{:>3}  {:<6} {:>12} {:>12} {:>8}",
+                s.day(),
+                stage,
+                bench::format_duration(now),
+                before,
+                change
+            );
+        }
+    }
+
+    if let Some(name) = &args.save_baseline {
+        let path = baseline_path(&root, name);
+        saved.save(&path)?;
+        println!("Saved baseline {:?} to {}", name, path.display());
+    }
+    Ok(())
+}
+
+fn describe_change(before: Duration, now: Duration) -> String {
+    let change = bench::change_percent(before, now);
+    match change.abs() < NOISE_PERCENT {
+        true => "~".to_string(),
+        false => format!("{:+.1}%", change),
+    }
+}
+
+fn baseline_path(root: &Path, name: &str) -> PathBuf {
+    let target = match std::env::var_os("CARGO_TARGET_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => root.join("target"),
+    };
+    target.join("aoc-bench").join(format!("{}.baseline", name))
+}
@@ -0,0 +1,466 @@
+use aoc_core::Part;
+use aoc_core::output::input_hash;
+use std::cell::Cell;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+/// Server to talk to instead of [`DEFAULT_BASE_URL`], e.g. a local mock.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+/// Value of the `session` cookie from a logged in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2022;
+/// Least time between two requests to the server, across runs when there's
+/// a cache directory to remember the last one in.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+const USER_AGENT: &str = "github.com/jwebmeister/aoc2022";
+
+#[derive(Error, Debug)]
+pub enum ClientError {
+    #[error("No session token, set {SESSION_VAR} to the session cookie from adventofcode.com")]
+    NoSession,
+    #[error("Request to {0} failed, {1}")]
+    Request(String, ureq::Error),
+    #[error("{0} responded with status {1}, {2}")]
+    Status(String, u16, String),
+    #[error("Unable to read {0}, {1}")]
+    Read(PathBuf, std::io::Error),
+    #[error("Unable to write {0}, {1}")]
+    Write(PathBuf, std::io::Error),
+}
+
+/// What the server made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reply {
+    Correct,
+    Incorrect,
+    /// Answered too soon after a wrong answer, try again later.
+    TooSoon,
+    /// The part was already solved, or part 1 hasn't been yet.
+    WrongLevel,
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub reply: Reply,
+    /// The server's message, without markup.
+    pub message: String,
+}
+
+impl Submission {
+    pub fn from_html(html: &str) -> Self {
+        let message = strip_tags(article(html));
+        let reply = if message.contains("That's the right answer") {
+            Reply::Correct
+        } else if message.contains("That's not the right answer") {
+            Reply::Incorrect
+        } else if message.contains("You gave an answer too recently") {
+            Reply::TooSoon
+        } else if message.contains("You don't seem to be solving the right level") {
+            Reply::WrongLevel
+        } else {
+            Reply::Other
+        };
+        Submission { reply, message }
+    }
+
+    /// Whether submitting the same answer again would get the same reply.
+    fn is_final(&self) -> bool {
+        matches!(self.reply, Reply::Correct | Reply::Incorrect)
+    }
+}
+
+fn article(html: &str) -> &str {
+    let Some(start) = html.find("<article") else {
+        return html;
+    };
+    let rest = &html[start..];
+    let body = rest.find('>').map_or(rest, |i| &rest[i + 1..]);
+    body.find("</article>").map_or(body, |end| &body[..end])
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Directory to keep the server's responses in, overriding the default.
+pub const CACHE_DIR_VAR: &str = "AOC_RESPONSE_DIR";
+
+/// [`CACHE_DIR_VAR`] if set, otherwise `aoc2022-responses` in the user's
+/// cache directory, apart from the cached answers `aoc clear-cache` removes.
+pub fn default_cache_dir() -> Option<PathBuf> {
+    if let Some(dir) = std::env::var_os(CACHE_DIR_VAR) {
+        return Some(PathBuf::from(dir));
+    }
+    Some(aoc_core::cache::user_cache_dir()?.join("aoc2022-responses"))
+}
+
+/// Downloads inputs and submits answers, keeping the responses in
+/// `cache_dir` so the server is asked about each at most once.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    /// Subdirectory of `cache_dir` for this server and session, so one's
+    /// responses are never given for another.
+    cache_scope: String,
+    cache_dir: Option<PathBuf>,
+    min_interval: Duration,
+    last_request: Cell<Option<SystemTime>>,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+        let base_url = base_url.into().trim_end_matches('/').to_string();
+        let session = session.into();
+        let cache_scope = input_hash(&format!("{}\n{}", base_url, session))[..16].to_string();
+        Client {
+            agent,
+            base_url,
+            session,
+            cache_scope,
+            cache_dir: None,
+            min_interval: MIN_INTERVAL,
+            last_request: Cell::new(None),
+        }
+    }
+
+    /// Talks to `base_url`, or [`BASE_URL_VAR`] if not given, then
+    /// [`DEFAULT_BASE_URL`], with the session token from [`SESSION_VAR`].
+    pub fn from_env(base_url: Option<String>) -> Result<Self, ClientError> {
+        let session = std::env::var(SESSION_VAR).map_err(|_| ClientError::NoSession)?;
+        let session = session.trim();
+        if session.is_empty() {
+            return Err(ClientError::NoSession);
+        }
+        let base_url = base_url
+            .or_else(|| std::env::var(BASE_URL_VAR).ok())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        Ok(Client::new(base_url, session))
+    }
+
+    pub fn with_cache_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(dir.into());
+        self
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    /// The input for `day`, from the cache if it's been downloaded before.
+    pub fn input(&self, day: u8) -> Result<String, ClientError> {
+        let key = format!("input-day{}", day);
+        if let Some(input) = self.cached(&key)? {
+            return Ok(input);
+        }
+        let url = format!("{}/input", self.day_url(day));
+        self.wait_turn();
+        log::info!("GET {}", url);
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .call();
+        let input = read_response(&url, response)?;
+        self.cache(&key, &input)?;
+        Ok(input)
+    }
+
+    /// Saves the input for `day` as `dayN.txt` in `dir`, where
+    /// `aoc_core::InputSource::Default` looks for it.
+    pub fn download(&self, day: u8, dir: &Path) -> Result<PathBuf, ClientError> {
+        let input = self.input(day)?;
+        let path = dir.join(format!("day{}.txt", day));
+        std::fs::create_dir_all(dir)
+            .and_then(|_| std::fs::write(&path, input))
+            .map_err(|e| ClientError::Write(path.clone(), e))?;
+        Ok(path)
+    }
+
+    /// Submits `answer` to `part` of `day`. A correct or incorrect reply is
+    /// cached, so the same answer isn't sent twice.
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Submission, ClientError> {
+        let key = format!("answer-day{}-part{}-{}", day, part, input_hash(answer));
+        if let Some(html) = self.cached(&key)? {
+            return Ok(Submission::from_html(&html));
+        }
+        let url = format!("{}/answer", self.day_url(day));
+        self.wait_turn();
+        log::info!("POST {} part {} answer {:?}", url, part, answer);
+        let level = part.to_string();
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .send_form([("level", level.as_str()), ("answer", answer)]);
+        let html = read_response(&url, response)?;
+        let submission = Submission::from_html(&html);
+        if submission.is_final() {
+            self.cache(&key, &html)?;
+        }
+        Ok(submission)
+    }
+
+    fn cached(&self, key: &str) -> Result<Option<String>, ClientError> {
+        let Some(dir) = &self.cache_dir else {
+            return Ok(None);
+        };
+        let path = dir.join(&self.cache_scope).join(key);
+        match std::fs::read_to_string(&path) {
+            Ok(body) => {
+                log::debug!("using cached response {}", path.display());
+                Ok(Some(body))
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(ClientError::Read(path, e)),
+        }
+    }
+
+    fn cache(&self, key: &str, body: &str) -> Result<(), ClientError> {
+        let Some(dir) = &self.cache_dir else {
+            return Ok(());
+        };
+        let dir = dir.join(&self.cache_scope);
+        let path = dir.join(key);
+        std::fs::create_dir_all(&dir)
+            .and_then(|_| std::fs::write(&path, body))
+            .map_err(|e| ClientError::Write(path, e))
+    }
+
+    /// Sleeps until `min_interval` has passed since the last request.
+    fn wait_turn(&self) {
+        let stamp = self.cache_dir.as_ref().map(|dir| dir.join("last-request"));
+        let last = self.last_request.get().or_else(|| {
+            let millis = std::fs::read_to_string(stamp.as_ref()?).ok()?;
+            Some(UNIX_EPOCH + Duration::from_millis(millis.trim().parse().ok()?))
+        });
+        if let Some(since) = last.and_then(|last| last.elapsed().ok())
+            && since < self.min_interval
+        {
+            let wait = self.min_interval - since;
+            log::info!("waiting {:?} between requests", wait);
+            std::thread::sleep(wait);
+        }
+
+        let now = SystemTime::now();
+        self.last_request.set(Some(now));
+        if let Some(stamp) = stamp {
+            let millis = now
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis();
+            if let Err(e) = std::fs::create_dir_all(stamp.parent().unwrap_or(Path::new(".")))
+                .and_then(|_| std::fs::write(&stamp, millis.to_string()))
+            {
+                log::warn!("Unable to write {}, {}", stamp.display(), e);
+            }
+        }
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+) -> Result<String, ClientError> {
+    let mut response = response.map_err(|e| ClientError::Request(url.to_string(), e))?;
+    let status = response.status().as_u16();
+    let body = response
+        .body_mut()
+        .read_to_string()
+        .map_err(|e| ClientError::Request(url.to_string(), e))?;
+    log::debug!("{} responded with {}, {} bytes", url, status, body.len());
+    if status != 200 {
+        let reason = body.lines().next().unwrap_or_default().trim().to_string();
+        return Err(ClientError::Status(url.to_string(), status, reason));
+    }
+    Ok(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+    use std::time::Instant;
+
+    /// Serves `responses` in order, one per connection, returning the
+    /// requests it was sent.
+    fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.push(request);
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (base_url, handle)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-client-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    const CORRECT: &str = "<html><main><article><p>That's the right answer! \
+                           You are <em>one gold star</em> closer.</p></article></main></html>";
+    const TOO_SOON: &str = "<article><p>You gave an answer too recently; \
+                            you have 41s left to wait.</p></article>";
+
+    #[test]
+    fn download_works() {
+        let (base_url, server) = mock_server(vec![(200, "1000\n2000\n")]);
+        let dir = temp_dir("download");
+        let client = Client::new(base_url + "/", "abc")
+            .with_cache_dir(dir.join("cache"))
+            .with_min_interval(Duration::ZERO);
+
+        let path = client.download(1, &dir.join("inputs")).unwrap();
+        assert_eq!(dir.join("inputs").join("day1.txt"), path);
+        assert_eq!("1000\n2000\n", std::fs::read_to_string(&path).unwrap());
+        // served from the cache, the server only answers once
+        assert_eq!("1000\n2000\n", client.input(1).unwrap());
+
+        let requests = server.join().unwrap();
+        assert_eq!(1, requests.len());
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("session=abc"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn cache_is_kept_per_server_and_session() {
+        let (base_url, server) = mock_server(vec![(200, "1\n"), (200, "2\n")]);
+        let dir = temp_dir("scope");
+        let client = |session| {
+            Client::new(base_url.clone(), session)
+                .with_cache_dir(&dir)
+                .with_min_interval(Duration::ZERO)
+        };
+        assert_eq!("1\n", client("abc").input(1).unwrap());
+        assert_eq!("2\n", client("def").input(1).unwrap());
+        assert_eq!("1\n", client("abc").input(1).unwrap());
+        assert_eq!(2, server.join().unwrap().len());
+
+        let other = Client::new("http://127.0.0.1:1", "abc").with_cache_dir(&dir);
+        assert!(other.cached("input-day1").unwrap().is_none());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn submit_works() {
+        let (base_url, server) =
+            mock_server(vec![(200, CORRECT), (200, TOO_SOON), (200, TOO_SOON)]);
+        let dir = temp_dir("submit");
+        let client = Client::new(base_url, "abc")
+            .with_cache_dir(&dir)
+            .with_min_interval(Duration::ZERO);
+
+        let submission = client.submit(7, Part::One, "42").unwrap();
+        assert_eq!(Reply::Correct, submission.reply);
+        assert_eq!(
+            "That's the right answer! You are one gold star closer.",
+            submission.message
+        );
+        assert_eq!(submission, client.submit(7, Part::One, "42").unwrap());
+
+        assert_eq!(
+            Reply::TooSoon,
+            client.submit(7, Part::Two, "9").unwrap().reply
+        );
+        assert_eq!(
+            Reply::TooSoon,
+            client.submit(7, Part::Two, "9").unwrap().reply
+        );
+
+        let requests = server.join().unwrap();
+        assert_eq!(3, requests.len());
+        assert!(requests[0].starts_with("POST /2022/day/7/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("level=1&answer=42"));
+        assert!(requests[1].ends_with("level=2&answer=9"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn requests_are_rate_limited() {
+        let (base_url, server) = mock_server(vec![(200, "1\n"), (404, "Not found\n")]);
+        let client = Client::new(base_url, "abc").with_min_interval(Duration::from_millis(200));
+
+        let start = Instant::now();
+        assert_eq!("1\n", client.input(1).unwrap());
+        assert!(matches!(
+            client.input(26),
+            Err(ClientError::Status(_, 404, reason)) if reason == "Not found"
+        ));
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        assert_eq!(2, server.join().unwrap().len());
+    }
+
+    #[test]
+    fn submission_from_html_works() {
+        let html = "<article><p>That's not the right answer; your answer is too low.</p></article>";
+        assert_eq!(Reply::Incorrect, Submission::from_html(html).reply);
+        let html = "<article><p>You don't seem to be solving the right level.</p></article>";
+        assert_eq!(Reply::WrongLevel, Submission::from_html(html).reply);
+        let submission = Submission::from_html("Something else");
+        assert_eq!(Reply::Other, submission.reply);
+        assert_eq!("Something else", submission.message);
+    }
+}
@@ -0,0 +1,284 @@
+#[cfg(feature = "client")]
+pub mod client;
+
+use aoc_core::{Answers, BoxedError, Cache, InputSource, Part, Record, Solver, Verdict};
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+pub static SOLVERS: [&dyn Solver; 12] = [
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+    &day9::Day9,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+];
+
+pub fn solver(day: u8) -> Option<&'static dyn Solver> {
+    SOLVERS.iter().copied().find(|s| s.day() == day)
+}
+
+/// Solves both parts of `input` and checks them against its answers file.
+pub fn verify(s: &dyn Solver, input: &Path) -> Result<Vec<(Part, Verdict)>, BoxedError> {
+    let buffer = InputSource::Path(input.to_path_buf()).read(s.day())?;
+    let answers = Answers::load(&Answers::path_for(input))?.unwrap_or_default();
+    Ok(Part::ALL
+        .into_iter()
+        .map(|part| {
+            (
+                part,
+                Verdict::check(answers.get(part), s.solve(part, &buffer)),
+            )
+        })
+        .collect())
+}
+
+/// Solves both parts of `input` and records them in its answers file.
+pub fn record_answers(s: &dyn Solver, input: &Path) -> Result<Answers, BoxedError> {
+    let buffer = InputSource::Path(input.to_path_buf()).read(s.day())?;
+    let mut answers = Answers::default();
+    for part in Part::ALL {
+        answers.set(part, s.solve(part, &buffer)?);
+    }
+    answers.save(&Answers::path_for(input))?;
+    Ok(answers)
+}
+
+/// Result of solving one part of one day.
+pub struct Outcome {
+    pub day: u8,
+    pub part: Part,
+    pub record: Result<Record, BoxedError>,
+    /// The answer came from the cache rather than the solver.
+    pub cached: bool,
+}
+
+/// Solves one part of `input`, or looks the answer up in `cache` if it was
+/// worked out before. New answers are added to the cache.
+pub fn solve_part(s: &dyn Solver, part: Part, input: &str, cache: Option<&Cache>) -> Outcome {
+    let mut cached = false;
+    let record = Record::timed(s.day(), part, input, || {
+        let Some(cache) = cache else {
+            return s.solve(part, input);
+        };
+        if let Some(answer) = cache.get(s, part, input)? {
+            cached = true;
+            return Ok(answer);
+        }
+        let answer = s.solve(part, input)?;
+        cache.put(s, part, input, &answer)?;
+        Ok(answer)
+    });
+    Outcome {
+        day: s.day(),
+        part,
+        record,
+        cached,
+    }
+}
+
+/// Solves `parts` of each day on the input from `read_input`, sharing the
+/// work between `threads` threads. Outcomes are returned in day and part
+/// order.
+pub fn run_parallel<F>(
+    solvers: &[&dyn Solver],
+    parts: &[Part],
+    threads: usize,
+    cache: Option<&Cache>,
+    read_input: F,
+) -> Vec<Outcome>
+where
+    F: Fn(u8) -> Result<String, BoxedError> + Sync,
+{
+    let jobs: Vec<(&dyn Solver, Part)> = solvers
+        .iter()
+        .flat_map(|&s| parts.iter().map(move |&part| (s, part)))
+        .collect();
+    let next_job = AtomicUsize::new(0);
+    let outcomes = Mutex::new(Vec::with_capacity(jobs.len()));
+
+    std::thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, jobs.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(&(s, part)) = jobs.get(next_job.fetch_add(1, Ordering::Relaxed)) {
+                    let outcome = match read_input(s.day()) {
+                        Ok(input) => solve_part(s, part, &input, cache),
+                        Err(e) => Outcome {
+                            day: s.day(),
+                            part,
+                            record: Err(e),
+                            cached: false,
+                        },
+                    };
+                    outcomes.lock().unwrap().push(outcome);
+                }
+            });
+        }
+    });
+
+    let mut outcomes = outcomes.into_inner().unwrap();
+    outcomes.sort_by_key(|o| (o.day, o.part));
+    outcomes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solvers_are_in_day_order() {
+        for (idx, s) in SOLVERS.iter().enumerate() {
+            assert_eq!(idx + 1, s.day() as usize);
+        }
+    }
+
+    #[test]
+    fn solver_works() {
+        assert_eq!(7, solver(7).unwrap().day());
+        assert!(solver(0).is_none());
+        assert!(solver(13).is_none());
+    }
+
+    #[test]
+    fn run_parallel_works() {
+        let solvers = [solver(1).unwrap(), solver(4).unwrap(), solver(6).unwrap()];
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let read_input = |day| {
+            let path = root.join(format!("day{}/src/input.txt", day));
+            Ok(InputSource::Path(path).read(day)?)
+        };
+        let outcomes = run_parallel(&solvers, &Part::ALL, 4, None, read_input);
+        let answers: Vec<_> = outcomes
+            .iter()
+            .map(|o| (o.day, o.part, o.record.as_ref().unwrap().answer.as_str()))
+            .collect();
+        assert_eq!(
+            vec![
+                (1, Part::One, "72017"),
+                (1, Part::Two, "212520"),
+                (4, Part::One, "507"),
+                (4, Part::Two, "897"),
+                (6, Part::One, "1892"),
+                (6, Part::Two, "2313"),
+            ],
+            answers
+        );
+    }
+
+    #[test]
+    fn solve_part_uses_cache() {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-{}", std::process::id()));
+        let cache = Cache::new(&dir);
+        let s = solver(1).unwrap();
+        let input = "1\n2\n\n4\n\n";
+
+        let outcome = solve_part(s, Part::One, input, Some(&cache));
+        assert_eq!("4", outcome.record.unwrap().answer);
+        assert!(!outcome.cached);
+
+        cache.put(s, Part::One, input, "planted").unwrap();
+        let outcome = solve_part(s, Part::One, input, Some(&cache));
+        assert_eq!("planted", outcome.record.unwrap().answer);
+        assert!(outcome.cached);
+
+        let outcome = solve_part(s, Part::One, input, None);
+        assert_eq!("4", outcome.record.unwrap().answer);
+        cache.clear().unwrap();
+    }
+
+    #[test]
+    fn examples_match_their_answers() {
+        for s in SOLVERS {
+            assert!(!s.examples().is_empty(), "day {} has no examples", s.day());
+            for (idx, example) in s.examples().iter().enumerate() {
+                for part in Part::ALL {
+                    let verdict =
+                        Verdict::check(example.answer(part), s.solve(part, example.input));
+                    assert!(
+                        verdict.is_ok(),
+                        "day {} example {} part {}: {}",
+                        s.day(),
+                        idx + 1,
+                        part,
+                        verdict
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bundled_inputs_match_known_answers() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        for s in SOLVERS {
+            let input = root.join(format!("day{}/src/input.txt", s.day()));
+            for (part, verdict) in verify(s, &input).unwrap() {
+                assert_eq!(Verdict::Pass, verdict, "day {} part {}", s.day(), part);
+            }
+        }
+    }
+
+    #[test]
+    fn generated_inputs_solve() {
+        for s in SOLVERS {
+            for seed in 0..5 {
+                let input = aoc_gen::generate(s.day(), 30, seed).unwrap();
+                for part in Part::ALL {
+                    if let Err(e) = s.solve(part, &input) {
+                        panic!(
+                            "day {} part {} seed {}: {}\n{}",
+                            s.day(),
+                            part,
+                            seed,
+                            e,
+                            input
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Feeds every day broken versions of generated inputs, plus random
+    /// text, and checks it fails with an error instead of panicking.
+    #[test]
+    fn broken_inputs_never_panic() {
+        use aoc_gen::{GenRng, fuzz};
+        use rand::SeedableRng;
+        use std::panic::AssertUnwindSafe;
+
+        for s in SOLVERS {
+            let mut rng = GenRng::seed_from_u64(s.day() as u64);
+            for seed in 0..300 {
+                let input = match seed % 4 {
+                    0 => fuzz::arbitrary(&mut rng, 60),
+                    _ => fuzz::mutate(&mut rng, &aoc_gen::generate(s.day(), 10, seed).unwrap()),
+                };
+                let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                    if s.parse_only(&input).is_ok() {
+                        for part in Part::ALL {
+                            let _ = s.solve(part, &input);
+                        }
+                    }
+                }));
+                assert!(result.is_ok(), "day {} panicked on {:?}", s.day(), input);
+            }
+        }
+    }
+
+    #[test]
+    fn day12_grid_round_trips() {
+        for seed in 0..20 {
+            let input = aoc_gen::generate(12, 30 + seed as usize, seed).unwrap();
+            let grid = day12::parse_into_grid(input.as_bytes()).unwrap();
+            assert_eq!(format!("\n{}", input), format!("{:?}", grid));
+        }
+    }
+}
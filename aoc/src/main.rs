@@ -0,0 +1,422 @@
+use aoc::{SOLVERS, solver};
+use aoc_core::{BoxedError, Cache, Format, InputSource, Part, Solver, Verdict, bench};
+use clap::{Args, Parser, Subcommand};
+use std::time::{Duration, Instant};
+use std::{path::PathBuf, process::ExitCode};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every day in parallel with --all
+    Run(RunArgs),
+    /// Check answers against those recorded next to each input
+    Verify(VerifyArgs),
+    /// Print a random input for a day
+    Gen(GenArgs),
+    /// List the days that have solutions
+    List,
+    /// Remove every cached answer
+    ClearCache,
+    /// Download a day's input into the input directory
+    #[cfg(feature = "client")]
+    Download(DownloadArgs),
+    /// Submit the answer to one part of a day
+    #[cfg(feature = "client")]
+    Submit(SubmitArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to solve
+    #[arg(short, long, required_unless_present = "all")]
+    day: Option<u8>,
+    /// Solve every day
+    #[arg(long, conflicts_with_all = ["day", "input"])]
+    all: bool,
+    /// Solve the day's examples from the puzzle text instead, checking the
+    /// answers given for them
+    #[arg(short, long, requires = "day", conflicts_with_all = ["all", "input"])]
+    example: bool,
+    /// Only solve this part, 1 or 2
+    #[arg(short, long)]
+    part: Option<Part>,
+    /// Input file, or - to read stdin. Defaults to $AOC_INPUT_DIR/dayN.txt,
+    /// then the day's bundled input
+    #[arg(short, long)]
+    input: Option<InputSource>,
+    /// Print answers as text, or as one JSON record per line
+    #[arg(short, long, default_value = "text")]
+    format: Format,
+    /// Threads to solve with when running every day, defaults to one per CPU
+    #[arg(short, long, requires = "all")]
+    jobs: Option<usize>,
+    /// Solve again even if the answer for this input is cached
+    #[arg(long)]
+    no_cache: bool,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify this day
+    #[arg(short, long)]
+    day: Option<u8>,
+    /// Input file to verify, its answers are read from the same path with
+    /// an .answers extension
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+    /// Record the current answers instead of checking them
+    #[arg(long)]
+    update: bool,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    /// Day to generate an input for
+    #[arg(short, long)]
+    day: u8,
+    /// How big an input to generate, see `aoc_gen::SIZES` for what it counts
+    #[arg(short, long, default_value_t = 100)]
+    size: usize,
+    /// Seed for the input, random if not given
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+#[cfg(feature = "client")]
+#[derive(Args)]
+struct ClientArgs {
+    /// Server to talk to, defaults to $AOC_BASE_URL, then adventofcode.com
+    #[arg(long)]
+    base_url: Option<String>,
+    /// Ask the server again even if its response is cached
+    #[arg(long)]
+    no_cache: bool,
+}
+
+#[cfg(feature = "client")]
+#[derive(Args)]
+struct DownloadArgs {
+    /// Day to download the input for
+    #[arg(short, long)]
+    day: u8,
+    /// Directory to save dayN.txt in, defaults to $AOC_INPUT_DIR
+    #[arg(long)]
+    dir: Option<PathBuf>,
+    /// Download again even if the input is already saved
+    #[arg(long)]
+    force: bool,
+    #[command(flatten)]
+    client: ClientArgs,
+}
+
+#[cfg(feature = "client")]
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day to submit an answer for
+    #[arg(short, long)]
+    day: u8,
+    /// Part to submit an answer for, 1 or 2
+    #[arg(short, long)]
+    part: Part,
+    /// Answer to submit, solved from the day's input if not given
+    answer: Option<String>,
+    /// Input to solve, as for `aoc run`
+    #[arg(short, long, conflicts_with = "answer")]
+    input: Option<InputSource>,
+    #[command(flatten)]
+    client: ClientArgs,
+}
+
+fn main() -> ExitCode {
+    env_logger::init();
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Gen(args) => generate(args),
+        Command::ClearCache => clear_cache(),
+        #[cfg(feature = "client")]
+        Command::Download(args) => download(args),
+        #[cfg(feature = "client")]
+        Command::Submit(args) => submit(args),
+        Command::List => {
+            for s in SOLVERS {
+                println!("{0:>2}  {1}", s.day(), s.title());
+            }
+            Ok(())
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: RunArgs) -> Result<(), BoxedError> {
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let cache = match args.no_cache {
+        true => None,
+        false => Cache::from_env(),
+    };
+
+    if args.all {
+        return run_all(&parts, args.jobs, args.format, cache.as_ref());
+    }
+
+    let Some(day) = args.day else {
+        return Err("No day given".into());
+    };
+    let s = solver(day).ok_or_else(|| format!("No solution for day {}", day))?;
+    if args.example {
+        return run_examples(s, &parts, args.format);
+    }
+    let input = args.input.unwrap_or_default().read(s.day())?;
+    for &part in &parts {
+        aoc::solve_part(s, part, &input, cache.as_ref())
+            .record?
+            .print(args.format);
+    }
+    Ok(())
+}
+
+fn run_examples(s: &dyn Solver, parts: &[Part], format: Format) -> Result<(), BoxedError> {
+    let mut num_failed = 0;
+    for (idx, example) in s.examples().iter().enumerate() {
+        for &part in parts {
+            let outcome = aoc::solve_part(s, part, example.input, None);
+            if let Ok(record) = &outcome.record {
+                record.print(format);
+            }
+            let answer = outcome.record.map(|r| r.answer);
+            let verdict = Verdict::check(example.answer(part), answer);
+            if !verdict.is_ok() {
+                eprintln!("Example {} part {}: {}", idx + 1, part, verdict);
+                num_failed += 1;
+            }
+        }
+    }
+    match num_failed {
+        0 => Ok(()),
+        _ => Err(format!("{} example answer(s) were wrong", num_failed).into()),
+    }
+}
+
+fn run_all(
+    parts: &[Part],
+    jobs: Option<usize>,
+    format: Format,
+    cache: Option<&Cache>,
+) -> Result<(), BoxedError> {
+    let threads = match jobs {
+        Some(jobs) => jobs,
+        None => std::thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+    let start = Instant::now();
+    let outcomes = aoc::run_parallel(&SOLVERS, parts, threads, cache, |day| {
+        Ok(InputSource::Default.read(day)?)
+    });
+    let wall_time = start.elapsed();
+
+    for outcome in &outcomes {
+        match &outcome.record {
+            Ok(record) => record.print(format),
+            Err(e) => eprintln!("Day {} part {} failed: {}", outcome.day, outcome.part, e),
+        }
+    }
+
+    // most expensive first; the table goes to stderr when stdout is JSON
+    let mut by_cost: Vec<_> = outcomes.iter().collect();
+    by_cost.sort_by_key(|o| {
+        std::cmp::Reverse(o.record.as_ref().map_or(Duration::MAX, |r| r.duration))
+    });
+    let mut table = format!(
+        "\n{:>3} {:>4} {:>12}  {}\n",
+        "day", "part", "time", "result"
+    );
+    for o in by_cost {
+        let (time, result) = match &o.record {
+            Ok(r) if o.cached => (bench::format_duration(r.duration), "cached"),
+            Ok(r) => (bench::format_duration(r.duration), "ok"),
+            Err(_) => (String::new(), "FAILED"),
+        };
+        table += &format!("{:>3} {:>4} {:>12}  {}\n", o.day, o.part, time, result);
+    }
+    let cpu_time: Duration = outcomes
+        .iter()
+        .filter_map(|o| o.record.as_ref().ok())
+        .map(|r| r.duration)
+        .sum();
+    table += &format!(
+        "{} threads, {} wall, {} solving\n",
+        threads,
+        bench::format_duration(wall_time),
+        bench::format_duration(cpu_time)
+    );
+    match format {
+        Format::Text => print!("{}", table),
+        Format::Json => eprint!("{}", table),
+    }
+
+    match outcomes.iter().filter(|o| o.record.is_err()).count() {
+        0 => Ok(()),
+        num_failed => Err(format!("{} part(s) failed", num_failed).into()),
+    }
+}
+
+fn generate(args: GenArgs) -> Result<(), BoxedError> {
+    let seed = match args.seed {
+        Some(seed) => seed,
+        None => {
+            let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?;
+            let seed = now.as_nanos() as u64;
+            eprintln!("seed {}", seed);
+            seed
+        }
+    };
+    print!("{}", aoc_gen::generate(args.day, args.size, seed)?);
+    Ok(())
+}
+
+fn clear_cache() -> Result<(), BoxedError> {
+    let cache = Cache::from_env()
+        .ok_or_else(|| format!("No cache directory, set {}", aoc_core::cache::CACHE_DIR_VAR))?;
+    let num_answers = cache.clear()?;
+    println!(
+        "Removed {} cached answer(s) from {}",
+        num_answers,
+        cache.dir().display()
+    );
+    Ok(())
+}
+
+fn verify(args: VerifyArgs) -> Result<(), BoxedError> {
+    let solvers = match args.day {
+        Some(day) => vec![solver(day).ok_or_else(|| format!("No solution for day {}", day))?],
+        None => SOLVERS.to_vec(),
+    };
+
+    let (mut num_passed, mut num_failed, mut num_unknown) = (0, 0, 0);
+    for s in solvers {
+        let input = match args.input.clone() {
+            Some(path) => path,
+            None => match aoc_core::input::find_default_input(s.day()) {
+                Some(path) => path,
+                None => {
+                    println!("Day {:>2}         skipped, no input found", s.day());
+                    continue;
+                }
+            },
+        };
+
+        if args.update {
+            aoc::record_answers(s, &input)?;
+            println!("Day {:>2}         recorded {}", s.day(), input.display());
+            continue;
+        }
+
+        let verdicts = match aoc::verify(s, &input) {
+            Ok(v) => v,
+            Err(e) => {
+                println!("Day {:>2}         FAILED, {}", s.day(), e);
+                num_failed += 1;
+                continue;
+            }
+        };
+        for (part, verdict) in verdicts {
+            println!("Day {:>2} part {}  {}", s.day(), part, verdict);
+            match verdict {
+                Verdict::Pass => num_passed += 1,
+                Verdict::Unknown(_) => num_unknown += 1,
+                _ => num_failed += 1,
+            }
+        }
+    }
+
+    if args.update {
+        return Ok(());
+    }
+    println!(
+        "{} passed, {} failed, {} with no known answer",
+        num_passed, num_failed, num_unknown
+    );
+    match num_failed {
+        0 => Ok(()),
+        _ => Err(format!("{} answer(s) did not verify", num_failed).into()),
+    }
+}
+
+#[cfg(feature = "client")]
+fn client(args: &ClientArgs) -> Result<aoc::client::Client, BoxedError> {
+    let client = aoc::client::Client::from_env(args.base_url.clone())?;
+    let cache_dir = aoc::client::default_cache_dir().filter(|_| !args.no_cache);
+    Ok(match cache_dir {
+        Some(dir) => client.with_cache_dir(dir),
+        None => client,
+    })
+}
+
+#[cfg(feature = "client")]
+fn download(args: DownloadArgs) -> Result<(), BoxedError> {
+    let dir = match args.dir {
+        Some(dir) => dir,
+        None => std::env::var_os(aoc_core::input::INPUT_DIR_VAR)
+            .map(PathBuf::from)
+            .ok_or_else(|| {
+                format!(
+                    "No input directory, pass --dir or set {}",
+                    aoc_core::input::INPUT_DIR_VAR
+                )
+            })?,
+    };
+    let existing = dir.join(format!("day{}.txt", args.day));
+    if existing.is_file() && !args.force {
+        eprintln!(
+            "{} already exists, pass --force to download it again",
+            existing.display()
+        );
+        return Ok(());
+    }
+    let path = client(&args.client)?.download(args.day, &dir)?;
+    eprintln!("Saved day {} input to {}", args.day, path.display());
+    Ok(())
+}
+
+#[cfg(feature = "client")]
+fn submit(args: SubmitArgs) -> Result<(), BoxedError> {
+    use aoc::client::Reply;
+
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => {
+            let s = solver(args.day).ok_or_else(|| format!("No solution for day {}", args.day))?;
+            let input = args.input.unwrap_or_default().read(s.day())?;
+            s.solve(args.part, &input)?
+        }
+    };
+    if answer.contains('\n') {
+        return Err(format!(
+            "Day {} part {} answer is drawn over several lines, read it and submit it by hand",
+            args.day, args.part
+        )
+        .into());
+    }
+    let submission = client(&args.client)?.submit(args.day, args.part, &answer)?;
+    println!("{}", submission.message);
+    match submission.reply {
+        Reply::Correct => Ok(()),
+        reply => Err(format!("Answer {:?} was not accepted, {:?}", answer, reply).into()),
+    }
+}
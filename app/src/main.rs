@@ -1,7 +1,10 @@
 #![warn(clippy::all, rust_2018_idioms)]
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")] // hide console window on Windows in release
 
-use day12::AppDay12;
+mod shell;
+mod visualizer;
+
+use shell::Shell;
 
 // When compiling natively:
 #[cfg(not(target_arch = "wasm32"))]
@@ -16,7 +19,7 @@ fn main() -> eframe::Result {
     eframe::run_native(
         "AoC 2022 app",
         native_options,
-        Box::new(|cc| Ok(Box::new(AppDay12::new(cc)))),
+        Box::new(|cc| Ok(Box::new(Shell::new(cc)))),
     )
 }
 
@@ -46,7 +49,7 @@ fn main() {
             .start(
                 canvas,
                 web_options,
-                Box::new(|cc| Ok(Box::new(AppDay12::new(cc)))),
+                Box::new(|cc| Ok(Box::new(Shell::new(cc)))),
             )
             .await;
 
@@ -0,0 +1,57 @@
+use crate::visualizer::{self, Visualizer};
+
+/// Days with a solution, each gets a tab whether or not it has a visualizer.
+const NUM_DAYS: u8 = 12;
+
+/// Top-level app: a tab per day above the selected day's visualizer.
+pub struct Shell {
+    visualizers: Vec<Box<dyn Visualizer>>,
+    selected: usize,
+}
+
+impl Shell {
+    /// Called once before the first frame.
+    pub fn new(_cc: &eframe::CreationContext<'_>) -> Self {
+        let visualizers = visualizer::all();
+        let selected = visualizers.len().saturating_sub(1);
+        Shell {
+            visualizers,
+            selected,
+        }
+    }
+}
+
+impl eframe::App for Shell {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        egui::TopBottomPanel::top("day_tabs").show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                for day in 1..=NUM_DAYS {
+                    let label = format!("Day {}", day);
+                    match self.visualizers.iter().position(|v| v.day() == day) {
+                        Some(idx) => {
+                            let resp = ui
+                                .selectable_label(self.selected == idx, label)
+                                .on_hover_text(self.visualizers[idx].title());
+                            if resp.clicked() {
+                                self.selected = idx;
+                            }
+                        }
+                        None => {
+                            ui.add_enabled(false, egui::SelectableLabel::new(false, label))
+                                .on_disabled_hover_text("No visualizer yet");
+                        }
+                    }
+                }
+            });
+        });
+
+        match self.visualizers.get_mut(self.selected) {
+            Some(v) => v.show(ctx),
+            None => {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    ui.label("No visualizers");
+                });
+            }
+        }
+    }
+}
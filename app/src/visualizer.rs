@@ -0,0 +1,28 @@
+use aoc_core::Solution;
+
+/// A day's interactive visualization, shown as one of the [`crate::shell::Shell`]'s tabs.
+pub trait Visualizer {
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    /// Adds the visualizer's panels to `ctx`, below the day tabs.
+    fn show(&mut self, ctx: &egui::Context);
+}
+
+impl Visualizer for day12::AppDay12 {
+    fn day(&self) -> u8 {
+        day12::Day12::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        day12::Day12::TITLE
+    }
+
+    fn show(&mut self, ctx: &egui::Context) {
+        day12::AppDay12::show(self, ctx);
+    }
+}
+
+/// Every visualizer, in day order.
+pub fn all() -> Vec<Box<dyn Visualizer>> {
+    vec![Box::new(day12::AppDay12::default())]
+}
@@ -0,0 +1,10 @@
+//! Day 1: Calorie Counting.
+
+mod mod_day1;
+mod stats;
+
+pub use mod_day1::{
+    Day1, EXAMPLE, Elf, GroupPolicy, Inventory, MyError, parse_groups, parse_inventory,
+    stream_top_k,
+};
+pub use stats::Stats;
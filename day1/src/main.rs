@@ -1,58 +1,66 @@
-use std::io::prelude::*;
+use aoc_core::output::HashingReader;
+use aoc_core::{BoxedError, DayArgs, Format, InputError, Part, Record};
+use std::process::ExitCode;
+use std::time::Instant;
 
-fn main() {
-    let filepath_input = "./src/input.txt";
-    let alt_filepath_input = "./day1/src/input.txt";
-    let file_input = match std::fs::File::open(filepath_input) {
-        Ok(file) => file,
-        Err(_) => match std::fs::File::open(alt_filepath_input) {
-            Ok(file) => file,
-            Err(e) => {
-                println!(
-                    "Unable to open input data file from {0} or {1}.",
-                    filepath_input, alt_filepath_input
-                );
-                println!("{}", e);
-                return;
-            }
-        },
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1).peekable();
+    let result = match args.peek().map(String::as_str) {
+        Some("stream") => stream(args.skip(1)),
+        Some("stats") => stats(args.skip(1)),
+        _ => return aoc_core::run::<day1::Day1>(),
     };
-
-    let reader = std::io::BufReader::new(file_input);
-
-    let mut elf_cal: usize = 0;
-    let mut elves: Vec<usize> = Vec::new();
-    for (num, line) in reader.lines().enumerate() {
-        match line {
-            Ok(line) => {
-                if line.is_empty() {
-                    elves.push(elf_cal);
-                    elf_cal = 0;
-                } else {
-                    let cal: usize = match line.parse() {
-                        Ok(cal) => cal,
-                        Err(e) => {
-                            println!("Error reading input on line {}", num);
-                            println!("{}", e);
-                            return;
-                        }
-                    };
-                    elf_cal += cal;
-                }
-            }
-            Err(e) => {
-                println!("Error reading input on line {}", num);
-                println!("{}", e);
-            }
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
         }
     }
+}
 
-    elves.sort();
-    let mut sum_top3_cals: usize = 0;
-    for cal in elves.iter().rev().take(3) {
-        sum_top3_cals += cal;
+/// `day1 stream [--input PATH|-] [--format json]` solves both parts a line
+/// at a time, for inputs too large to read into memory.
+fn stream<I: Iterator<Item = String>>(args: I) -> Result<(), BoxedError> {
+    aoc_core::init_logger();
+    let args = DayArgs::parse(args)?;
+    let start = Instant::now();
+    let mut reader = HashingReader::new(args.input.open_in_day_binary(1)?);
+    let top = day1::stream_top_k(&mut reader, 3, day1::GroupPolicy::default())?;
+    let part1 = top.first().copied().unwrap_or_default();
+    let part2 = top
+        .iter()
+        .try_fold(0usize, |sum, &total| sum.checked_add(total))
+        .ok_or("The top 3 elves' calories overflow")?;
+    for (part, answer) in [(Part::One, part1), (Part::Two, part2)] {
+        let record = Record {
+            day: 1,
+            part,
+            answer: answer.to_string(),
+            duration: start.elapsed(),
+            input_hash: reader.input_hash(),
+        };
+        record.print(args.format);
     }
+    Ok(())
+}
 
-    println!("Top 1 elf calories = {}", elves.last().unwrap());
-    println!("Sum top 3 elf calories = {}", sum_top3_cals);
+/// `day1 stats [--input PATH|-]` prints a summary of the elves' totals.
+fn stats<I: Iterator<Item = String>>(args: I) -> Result<(), BoxedError> {
+    aoc_core::init_logger();
+    let args = DayArgs::parse(args)?;
+    if args.format != Format::Text {
+        let reason = "stats are only printed as text".to_string();
+        return Err(InputError::InvalidValue("--format".to_string(), reason).into());
+    }
+    let input = args.input.read_in_day_binary(1)?;
+    // keep the elves from blank lines in a row so they're reported
+    let policy = day1::GroupPolicy {
+        collapse_blank_lines: false,
+        ..Default::default()
+    };
+    let inventory = day1::parse_groups(&mut input.as_bytes(), policy)?;
+    let stats = day1::Stats::new(&inventory).ok_or("No elves in the input")?;
+    print!("{}", stats);
+    Ok(())
 }
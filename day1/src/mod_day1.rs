@@ -0,0 +1,341 @@
+use aoc_core::{Example, Solution};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::num::IntErrorKind;
+use std::ops::Range;
+use thiserror::Error;
+
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+    const VERSION: u32 = 2;
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE, "24000", "45000")];
+    type Parsed = Inventory;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = MyError;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        let mut reader = std::io::BufReader::new(input.as_bytes());
+        parse_inventory(&mut reader)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Self::Answer1, Self::Error> {
+        parsed.sum_top_k(1)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Self::Answer2, Self::Error> {
+        parsed.sum_top_k(3)
+    }
+}
+
+/// Lines are counted from 0, as in [`Elf::lines`], but shown counting from 1.
+#[derive(Error, Debug)]
+pub enum MyError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("Error reading input on line {line}, {1}", line = .0 + 1)]
+    ParseLine(usize, std::num::ParseIntError),
+    #[error("Negative calories on line {line}, {1}", line = .0 + 1)]
+    Negative(usize, String),
+    #[error("Too many calories on line {line}, they overflow", line = .0 + 1)]
+    Overflow(usize),
+}
+
+/// How the input is split into elves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GroupPolicy {
+    /// Count the last elf even without a blank line after it.
+    pub include_final_group: bool,
+    /// Treat blank lines in a row as one, rather than as elves carrying
+    /// nothing.
+    pub collapse_blank_lines: bool,
+}
+
+impl GroupPolicy {
+    /// How the input was first parsed, only ending an elf on a blank line.
+    pub const LEGACY: GroupPolicy = GroupPolicy {
+        include_final_group: false,
+        collapse_blank_lines: false,
+    };
+}
+
+impl Default for GroupPolicy {
+    fn default() -> Self {
+        GroupPolicy {
+            include_final_group: true,
+            collapse_blank_lines: true,
+        }
+    }
+}
+
+/// One elf's food items, in calories.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Elf {
+    pub items: Vec<usize>,
+    /// The input lines the items were on, counting from 0.
+    pub lines: Range<usize>,
+}
+
+impl Elf {
+    pub fn total(&self) -> usize {
+        self.items.iter().sum()
+    }
+}
+
+/// The elves in input order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Inventory {
+    pub elves: Vec<Elf>,
+}
+
+impl Inventory {
+    pub fn totals(&self) -> Vec<usize> {
+        self.elves.iter().map(Elf::total).collect()
+    }
+
+    /// Index and total of the `k` elves carrying the most calories, most
+    /// first. Ties go to the elf listed first.
+    pub fn top_k(&self, k: usize) -> Vec<(usize, usize)> {
+        let mut totals = self.totals().into_iter().enumerate().collect::<Vec<_>>();
+        totals.sort_by(|(a_idx, a), (b_idx, b)| b.cmp(a).then(a_idx.cmp(b_idx)));
+        totals.truncate(k);
+        log::debug!("top {} of {} elves are {:?}", k, self.elves.len(), totals);
+        totals
+    }
+
+    /// Total calories of the `k` elves carrying the most, an error on the
+    /// last line of the elf whose total makes the sum overflow.
+    pub fn sum_top_k(&self, k: usize) -> Result<usize, MyError> {
+        self.top_k(k)
+            .into_iter()
+            .try_fold(0usize, |sum, (idx, total)| {
+                let line = self.elves[idx].lines.end.saturating_sub(1);
+                sum.checked_add(total).ok_or(MyError::Overflow(line))
+            })
+    }
+}
+
+pub fn parse_inventory<R: std::io::BufRead>(reader: &mut R) -> Result<Inventory, MyError> {
+    parse_groups(reader, GroupPolicy::default())
+}
+
+/// Splits the input into elves on blank lines, as `policy` says. Negative
+/// calories and totals too large for a `usize` are errors.
+pub fn parse_groups<R: std::io::BufRead>(
+    reader: &mut R,
+    policy: GroupPolicy,
+) -> Result<Inventory, MyError> {
+    let mut elves: Vec<Elf> = Vec::new();
+    for_each_group(reader, policy, |items, lines| {
+        let elf = Elf {
+            items: items.to_vec(),
+            lines,
+        };
+        log::trace!("elf {} carries {} calories", elves.len() + 1, elf.total());
+        elves.push(elf);
+    })?;
+    log::debug!("parsed {} elves", elves.len());
+    Ok(Inventory { elves })
+}
+
+/// The `k` largest elf totals, most first, the same as from
+/// [`Inventory::top_k`] but reading a line at a time and only keeping `k`
+/// totals, for inputs too large to hold in memory.
+pub fn stream_top_k<R: std::io::BufRead>(
+    reader: &mut R,
+    k: usize,
+    policy: GroupPolicy,
+) -> Result<Vec<usize>, MyError> {
+    let mut top: BinaryHeap<Reverse<usize>> = BinaryHeap::with_capacity(k + 1);
+    let mut num_elves = 0;
+    for_each_group(reader, policy, |items, _| {
+        num_elves += 1;
+        top.push(Reverse(items.iter().sum()));
+        if top.len() > k {
+            top.pop();
+        }
+    })?;
+    let top = top
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(total)| total)
+        .collect::<Vec<_>>();
+    log::debug!("top {} of {} elves carry {:?}", k, num_elves, top);
+    Ok(top)
+}
+
+/// Calls `f` with each elf's items and the lines they were on, reusing one
+/// line and one item buffer.
+fn for_each_group<R, F>(reader: &mut R, policy: GroupPolicy, mut f: F) -> Result<(), MyError>
+where
+    R: std::io::BufRead,
+    F: FnMut(&[usize], Range<usize>),
+{
+    let mut buffer = String::new();
+    let mut items: Vec<usize> = Vec::new();
+    let mut elf_cal: usize = 0;
+    let mut first_line = 0;
+    let mut num = 0;
+    loop {
+        buffer.clear();
+        if reader.read_line(&mut buffer)? == 0 {
+            break;
+        }
+        let line = buffer.strip_suffix('\n').unwrap_or(&buffer);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if line.is_empty() {
+            if !items.is_empty() || !policy.collapse_blank_lines {
+                f(&items, first_line..num);
+                items.clear();
+                elf_cal = 0;
+            }
+            first_line = num + 1;
+        } else {
+            let cal = parse_calories(num, line)?;
+            elf_cal = elf_cal.checked_add(cal).ok_or(MyError::Overflow(num))?;
+            items.push(cal);
+        }
+        num += 1;
+    }
+    if policy.include_final_group && !items.is_empty() {
+        f(&items, first_line..num);
+    }
+    Ok(())
+}
+
+fn parse_calories(num: usize, line: &str) -> Result<usize, MyError> {
+    let e = match line.parse() {
+        Ok(cal) => return Ok(cal),
+        Err(e) => e,
+    };
+    let digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    match e.kind() {
+        IntErrorKind::PosOverflow => Err(MyError::Overflow(num)),
+        _ if line.strip_prefix('-').is_some_and(digits) => {
+            Err(MyError::Negative(num, line.to_string()))
+        }
+        _ => Err(MyError::ParseLine(num, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_inventory_works() {
+        let mut reader = std::io::BufReader::new(EXAMPLE.as_bytes());
+        let inventory = parse_inventory(&mut reader).unwrap();
+        assert_eq!(vec![6000, 4000, 11000, 24000, 10000], inventory.totals());
+        assert_eq!(
+            Elf {
+                items: vec![5000, 6000],
+                lines: 6..8
+            },
+            inventory.elves[2]
+        );
+    }
+
+    #[test]
+    fn parse_groups_works() {
+        let input = "\n1\n2\n\n\n\n3\n\n4";
+        let parse = |policy| {
+            parse_groups(&mut input.as_bytes(), policy)
+                .unwrap()
+                .totals()
+        };
+        assert_eq!(vec![3, 3, 4], parse(GroupPolicy::default()));
+        assert_eq!(vec![0, 3, 0, 0, 3], parse(GroupPolicy::LEGACY));
+        let policy = GroupPolicy {
+            include_final_group: false,
+            ..Default::default()
+        };
+        assert_eq!(vec![3, 3], parse(policy));
+
+        let inventory = parse_groups(&mut input.as_bytes(), GroupPolicy::default()).unwrap();
+        assert_eq!(1..3, inventory.elves[0].lines);
+        assert_eq!(8..9, inventory.elves[2].lines);
+
+        let parse = |input: &str| parse_groups(&mut input.as_bytes(), GroupPolicy::default());
+        assert!(matches!(parse("1\n\n-20\n"), Err(MyError::Negative(2, _))));
+        assert!(matches!(parse("1\n-\n"), Err(MyError::ParseLine(1, _))));
+        assert!(matches!(
+            parse("1\n99999999999999999999999\n"),
+            Err(MyError::Overflow(1))
+        ));
+        let input = format!("1\n{}\n", usize::MAX);
+        assert!(matches!(parse(&input), Err(MyError::Overflow(1))));
+        assert_eq!(
+            "Negative calories on line 3, -20",
+            parse("1\n\n-20\n").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn top_k_works() {
+        let mut reader = std::io::BufReader::new(EXAMPLE.as_bytes());
+        let inventory = parse_inventory(&mut reader).unwrap();
+        assert_eq!(vec![(3, 24000), (2, 11000), (4, 10000)], inventory.top_k(3));
+        assert_eq!(5, inventory.top_k(10).len());
+        assert!(inventory.top_k(0).is_empty());
+        assert_eq!(45000, inventory.sum_top_k(3).unwrap());
+
+        let inventory = Inventory {
+            elves: vec![
+                Elf {
+                    items: vec![1],
+                    lines: 0..1,
+                },
+                Elf {
+                    items: vec![1],
+                    lines: 2..3,
+                },
+            ],
+        };
+        assert_eq!(vec![(0, 1), (1, 1)], inventory.top_k(2));
+    }
+
+    #[test]
+    fn stream_top_k_works() {
+        let mut reader = std::io::BufReader::new(EXAMPLE.as_bytes());
+        let inventory = parse_inventory(&mut reader).unwrap();
+        for k in 0..7 {
+            let mut reader = std::io::BufReader::new(EXAMPLE.as_bytes());
+            let expected = inventory.top_k(k).into_iter().map(|(_, total)| total);
+            assert_eq!(
+                expected.collect::<Vec<_>>(),
+                stream_top_k(&mut reader, k, GroupPolicy::default()).unwrap()
+            );
+        }
+
+        let mut reader = std::io::BufReader::new("1\r\n2\r\n\r\n4\r\n\r\n".as_bytes());
+        assert_eq!(
+            vec![4, 3],
+            stream_top_k(&mut reader, 3, GroupPolicy::default()).unwrap()
+        );
+
+        let mut reader = std::io::BufReader::new("1000\n\nabc\n".as_bytes());
+        assert!(matches!(
+            stream_top_k(&mut reader, 3, GroupPolicy::default()),
+            Err(MyError::ParseLine(2, _))
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips() {
+        let inventory = Day1::parse(EXAMPLE).unwrap();
+        let json = serde_json::to_string(&inventory).unwrap();
+        assert!(
+            json.starts_with(r#"{"elves":[{"items":[1000,2000,3000],"lines":{"start":0,"end":3}}"#)
+        );
+        assert_eq!(inventory, serde_json::from_str(&json).unwrap());
+    }
+}
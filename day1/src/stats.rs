@@ -0,0 +1,203 @@
+use crate::Inventory;
+
+/// Summary of the calories the elves carry, see `day1 stats`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub count: usize,
+    pub min: usize,
+    pub max: usize,
+    pub mean: f64,
+    pub median: f64,
+    /// Indices of the elves carrying nothing, from blank lines in a row.
+    pub empty: Vec<usize>,
+    sorted: Vec<usize>,
+}
+
+impl Stats {
+    /// None when there are no elves.
+    pub fn new(inventory: &Inventory) -> Option<Self> {
+        let mut sorted = inventory.totals();
+        sorted.sort_unstable();
+        let (&min, &max) = (sorted.first()?, sorted.last()?);
+        let count = sorted.len();
+        let mean = sorted.iter().map(|&total| total as f64).sum::<f64>() / count as f64;
+        let median = match count % 2 {
+            0 => sorted[count / 2 - 1] as f64 / 2.0 + sorted[count / 2] as f64 / 2.0,
+            _ => sorted[count / 2] as f64,
+        };
+        let empty = inventory
+            .elves
+            .iter()
+            .enumerate()
+            .filter(|(_, elf)| elf.items.is_empty())
+            .map(|(idx, _)| idx)
+            .collect();
+        Some(Stats {
+            count,
+            min,
+            max,
+            mean,
+            median,
+            empty,
+            sorted,
+        })
+    }
+
+    /// The total that `p` percent of elves carry at most, by nearest rank.
+    /// `p` is clamped to 0..=100.
+    pub fn percentile(&self, p: f64) -> usize {
+        let rank = (p.clamp(0.0, 100.0) / 100.0 * self.count as f64).ceil() as usize;
+        self.sorted[rank.saturating_sub(1)]
+    }
+
+    /// Counts of elves in each of up to `bins` equal ranges of totals, from
+    /// `min` to `max`, as `(start, end, count)` with `end` exclusive except
+    /// in the last bin.
+    pub fn histogram(&self, bins: usize) -> Vec<(usize, usize, usize)> {
+        let bins = bins.clamp(1, (self.max - self.min).saturating_add(1));
+        let width = (self.max - self.min).div_ceil(bins).max(1);
+        let mut counts = vec![0; bins];
+        for total in &self.sorted {
+            let bin = ((total - self.min) / width).min(bins - 1);
+            counts[bin] += 1;
+        }
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(bin, count)| {
+                let start = self.min.saturating_add(bin.saturating_mul(width));
+                (start, start.saturating_add(width), count)
+            })
+            .collect()
+    }
+}
+
+const HISTOGRAM_BINS: usize = 10;
+const HISTOGRAM_WIDTH: usize = 40;
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "elves   {}", self.count)?;
+        writeln!(f, "min     {}", self.min)?;
+        writeln!(f, "max     {}", self.max)?;
+        writeln!(f, "mean    {:.1}", self.mean)?;
+        writeln!(f, "median  {:.1}", self.median)?;
+        for p in [25, 75, 90, 99] {
+            writeln!(f, "p{:<6} {}", p, self.percentile(p as f64))?;
+        }
+        match self.empty.is_empty() {
+            true => writeln!(f, "empty   none")?,
+            false => writeln!(f, "empty   {} at {:?}", self.empty.len(), self.empty)?,
+        }
+        let histogram = self.histogram(HISTOGRAM_BINS);
+        let most = histogram
+            .iter()
+            .map(|&(_, _, count)| count)
+            .max()
+            .unwrap_or(0);
+        let label_width = histogram
+            .last()
+            .map_or(0, |&(_, end, _)| end.to_string().len());
+        for (start, end, count) in histogram {
+            let bar = "#".repeat((count * HISTOGRAM_WIDTH).div_ceil(most.max(1)));
+            writeln!(
+                f,
+                "{:>w$}..{:<w$} |{} {}",
+                start,
+                end,
+                bar,
+                count,
+                w = label_width
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EXAMPLE, Elf, GroupPolicy, MyError, parse_groups, parse_inventory};
+
+    #[test]
+    fn stats_works() {
+        let mut reader = std::io::BufReader::new(EXAMPLE.as_bytes());
+        let stats = Stats::new(&parse_inventory(&mut reader).unwrap()).unwrap();
+        assert_eq!((5, 4000, 24000), (stats.count, stats.min, stats.max));
+        assert_eq!((11000.0, 10000.0), (stats.mean, stats.median));
+        assert_eq!(
+            [4000, 6000, 10000, 11000, 24000],
+            [0.0, 25.0, 50.0, 75.0, 100.0].map(|p| stats.percentile(p))
+        );
+        assert!(stats.empty.is_empty());
+        assert_eq!(
+            vec![(4000, 14000, 4), (14000, 24000, 1)],
+            stats.histogram(2)
+        );
+
+        let mut reader = std::io::BufReader::new("1\n\n\n3\n\n".as_bytes());
+        let stats = Stats::new(&parse_groups(&mut reader, GroupPolicy::LEGACY).unwrap()).unwrap();
+        assert_eq!(vec![1], stats.empty);
+        assert_eq!(1.0, stats.median);
+        assert_eq!(
+            vec![(0, 1, 1), (1, 2, 1), (2, 3, 0), (3, 4, 1)],
+            stats.histogram(10)
+        );
+
+        assert_eq!(None, Stats::new(&Inventory::default()));
+    }
+
+    #[test]
+    fn stats_near_usize_max_works() {
+        let elf = |total| Elf {
+            items: vec![total],
+            lines: 0..1,
+        };
+        let inventory = Inventory {
+            elves: vec![elf(usize::MAX), elf(usize::MAX - 2)],
+        };
+        let stats = Stats::new(&inventory).unwrap();
+        assert_eq!(usize::MAX as f64, stats.median);
+        assert_eq!(
+            vec![
+                (usize::MAX - 2, usize::MAX - 1, 1),
+                (usize::MAX - 1, usize::MAX, 0),
+                (usize::MAX, usize::MAX, 1)
+            ],
+            stats.histogram(10)
+        );
+
+        let inventory = Inventory {
+            elves: vec![elf(0), elf(usize::MAX)],
+        };
+        let stats = Stats::new(&inventory).unwrap();
+        let histogram = stats.histogram(10);
+        assert_eq!(10, histogram.len());
+        assert_eq!((0, 1), (histogram[0].0, histogram[0].2));
+        assert_eq!(1, histogram[9].2);
+        assert!(stats.to_string().contains(&usize::MAX.to_string()));
+    }
+
+    #[test]
+    fn sum_top_k_near_usize_max_overflows() {
+        let input = format!("{}\n\n{}\n", usize::MAX - 1, usize::MAX - 2);
+        let inventory = parse_inventory(&mut input.as_bytes()).unwrap();
+        assert_eq!(usize::MAX - 1, inventory.sum_top_k(1).unwrap());
+        let e = inventory.sum_top_k(2).unwrap_err();
+        assert!(matches!(e, MyError::Overflow(2)));
+        assert_eq!("Too many calories on line 3, they overflow", e.to_string());
+        assert!(Stats::new(&inventory).is_some());
+    }
+
+    #[test]
+    fn stats_display_works() {
+        let mut reader = std::io::BufReader::new(EXAMPLE.as_bytes());
+        let stats = Stats::new(&parse_inventory(&mut reader).unwrap()).unwrap();
+        let report = stats.to_string();
+        assert!(report.starts_with("elves   5\nmin     4000\n"));
+        assert!(report.contains("median  10000.0\n"));
+        assert!(report.contains(" 8000..10000 | 0\n"));
+        assert!(report.contains("10000..12000 |######################################## 2\n"));
+        assert!(report.ends_with("22000..24000 |#################### 1\n"));
+    }
+}
@@ -0,0 +1,8 @@
+//! Day 10: Cathode-Ray Tube.
+
+mod mod_day10;
+
+pub use mod_day10::{
+    CRT_HEIGHT, CRT_WIDTH, Command, Cpu, Crt, Day10, EXAMPLE, EXAMPLE_IMAGE, MyError,
+    lines_to_result, parse_lines_to_commands, run_commands,
+};
@@ -1,17 +1,114 @@
+use aoc_core::{Example, ParseError, Solution, parse::IResult};
 use nom::{
+    Finish, Parser,
     branch::alt,
     bytes::complete::tag,
     combinator::{all_consuming, map},
+    error::context,
     sequence::preceded,
-    Finish, IResult, Parser,
 };
 use std::{collections::VecDeque, io::prelude::*};
 use thiserror::Error;
 
-const CRT_WIDTH: usize = 40;
-const CRT_HEIGHT: usize = 6;
+pub const CRT_WIDTH: usize = 40;
+pub const CRT_HEIGHT: usize = 6;
 
-pub struct Crt([bool; CRT_WIDTH * CRT_HEIGHT]);
+pub const EXAMPLE: &str = include_str!("example.txt");
+/// What the CRT shows for [`EXAMPLE`].
+pub const EXAMPLE_IMAGE: &str = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Cathode-Ray Tube";
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE, "13140", EXAMPLE_IMAGE)];
+    type Parsed = VecDeque<Command>;
+    type Answer1 = i32;
+    type Answer2 = Crt;
+    type Error = MyError;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        let mut reader = std::io::BufReader::new(input.as_bytes());
+        parse_lines_to_commands(&mut reader)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Self::Answer1, Self::Error> {
+        let (v, _crt) = run_commands(parsed)?;
+        v.iter()
+            .try_fold(0i32, |sum, &n| sum.checked_add(n))
+            .ok_or(MyError::SignalOverflow)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Self::Answer2, Self::Error> {
+        let (_v, crt) = run_commands(parsed)?;
+        Ok(crt)
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Crt(pub [bool; CRT_WIDTH * CRT_HEIGHT]);
+
+impl std::fmt::Display for Crt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows = self
+            .0
+            .chunks(CRT_WIDTH)
+            .map(|row| {
+                row.iter()
+                    .map(|n| if *n { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        write!(f, "{}", rows.join("\n"))
+    }
+}
+
+/// Written as the image, rows of `#` and `.`, since serde can't derive for
+/// arrays this long.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Crt {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Crt {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let image = String::deserialize(deserializer)?;
+        let pixels = image
+            .lines()
+            .flat_map(|row| row.chars())
+            .collect::<Vec<_>>();
+        let mut crt = [false; CRT_WIDTH * CRT_HEIGHT];
+        if pixels.len() != crt.len() {
+            return Err(serde::de::Error::invalid_length(
+                pixels.len(),
+                &"a 40 by 6 image",
+            ));
+        }
+        for (lit, c) in crt.iter_mut().zip(pixels) {
+            *lit = match c {
+                '#' => true,
+                '.' => false,
+                c => {
+                    return Err(serde::de::Error::invalid_value(
+                        serde::de::Unexpected::Char(c),
+                        &"'#' or '.'",
+                    ));
+                }
+            };
+        }
+        Ok(Crt(crt))
+    }
+}
 
 impl std::fmt::Debug for Crt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -30,6 +127,7 @@ impl std::fmt::Debug for Crt {
 }
 
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Command {
     Noop,
     Addx(i32),
@@ -46,93 +144,123 @@ impl std::fmt::Debug for Command {
 
 #[derive(Error, Debug)]
 pub enum MyError {
-    #[error("parsing error, {0:?}")]
-    Parser(nom::error::ErrorKind),
+    #[error("parsing error, {0}")]
+    Parser(#[from] ParseError),
     #[error(transparent)]
     Io(#[from] std::io::Error),
+    #[error("Register x overflows on cycle {0}")]
+    Overflow(i32),
+    #[error("The sum of the signal strengths overflows")]
+    SignalOverflow,
 }
 
-impl<T> From<nom::error::Error<T>> for MyError {
-    fn from(err: nom::error::Error<T>) -> Self {
-        Self::Parser(err.code)
-    }
+pub fn lines_to_result<R: std::io::BufRead>(reader: &mut R) -> Result<(Vec<i32>, Crt), MyError> {
+    let commands = parse_lines_to_commands(reader)?;
+    run_commands(&commands)
 }
 
-pub fn lines_to_result<R: std::io::BufRead>(reader: &mut R) -> Result<(Vec<i32>, Crt), MyError> {
-    let mut v: Vec<i32> = Vec::new();
-    let mut crt = [false; CRT_WIDTH * CRT_HEIGHT];
-    let mut cycle: i32 = 1;
-    let mut x: i32 = 1;
-    for line in reader.lines() {
-        let l = line?;
-        match all_consuming(parse_command).parse(&l).finish() {
-            Ok((_, cmd)) => match cmd {
-                Command::Noop => {
-                    if (cycle - 20) % 40 == 0 {
-                        v.push(cycle * x);
-                    };
-                    if ((x - 1)..=(x + 1)).contains(&((cycle - 1) % 40))
-                        && (cycle as usize) <= crt.len()
-                    {
-                        crt[(cycle - 1) as usize] = true;
-                    };
-                    cycle += 1;
-                }
-                Command::Addx(n) => {
-                    if (cycle - 20) % 40 == 0 {
-                        v.push(cycle * x);
-                    };
-                    if ((x - 1)..=(x + 1)).contains(&((cycle - 1) % 40))
-                        && (cycle as usize) <= crt.len()
-                    {
-                        crt[(cycle - 1) as usize] = true;
-                    };
-                    cycle += 1;
-                    if (cycle - 20) % 40 == 0 {
-                        v.push(cycle * x);
-                    };
-                    if ((x - 1)..=(x + 1)).contains(&((cycle - 1) % 40))
-                        && (cycle as usize) <= crt.len()
-                    {
-                        crt[(cycle - 1) as usize] = true;
-                    };
-                    x += n;
-                    cycle += 1;
+pub fn run_commands<'a, I: IntoIterator<Item = &'a Command>>(
+    commands: I,
+) -> Result<(Vec<i32>, Crt), MyError> {
+    let mut cpu = Cpu::new(commands.into_iter().cloned().collect());
+    while cpu.tick()? {}
+    log::debug!(
+        "ran {} cycles, signal strengths {:?}",
+        cpu.cycle - 1,
+        cpu.signal_strengths
+    );
+    Ok((cpu.signal_strengths, cpu.crt))
+}
+
+/// The CPU and CRT, run one clock cycle at a time.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cpu {
+    pub commands: VecDeque<Command>,
+    /// Value an `addx` will add to `x` when it finishes on the next cycle.
+    pub adding: Option<i32>,
+    pub cycle: i32,
+    pub x: i32,
+    pub signal_strengths: Vec<i32>,
+    pub crt: Crt,
+}
+
+impl Cpu {
+    pub fn new(commands: VecDeque<Command>) -> Self {
+        Cpu {
+            commands,
+            adding: None,
+            cycle: 1,
+            x: 1,
+            signal_strengths: Vec::new(),
+            crt: Crt([false; CRT_WIDTH * CRT_HEIGHT]),
+        }
+    }
+
+    /// Runs one cycle, drawing a pixel. False once there are no commands
+    /// left, an error if `x` or the signal strength overflows.
+    pub fn tick(&mut self) -> Result<bool, MyError> {
+        let finishing = match self.adding.take() {
+            Some(n) => Some(n),
+            None => match self.commands.pop_front() {
+                Some(Command::Noop) => None,
+                Some(Command::Addx(n)) => {
+                    self.adding = Some(n);
+                    None
                 }
+                None => return Ok(false),
             },
-            Err(e) => return Err(e.into()),
         };
-    }
 
-    Ok((v, Crt(crt)))
+        let overflow = MyError::Overflow(self.cycle);
+        if (self.cycle - 20) % 40 == 0 {
+            let strength = self.cycle.checked_mul(self.x).ok_or(overflow)?;
+            self.signal_strengths.push(strength);
+        };
+        let column = ((self.cycle - 1) % 40) as i64;
+        if (column - self.x as i64).abs() <= 1 && (self.cycle as usize) <= self.crt.0.len() {
+            self.crt.0[(self.cycle - 1) as usize] = true;
+        };
+        if let Some(n) = finishing {
+            self.x = self.x.checked_add(n).ok_or(MyError::Overflow(self.cycle))?;
+        }
+        self.cycle += 1;
+        Ok(true)
+    }
 }
 
-pub fn _parse_lines_to_commands<R: std::io::BufRead>(
+pub fn parse_lines_to_commands<R: std::io::BufRead>(
     reader: &mut R,
 ) -> Result<VecDeque<Command>, MyError> {
     let mut v: VecDeque<Command> = VecDeque::new();
-    for line in reader.lines() {
+    for (idx, line) in reader.lines().enumerate() {
         let l = line?;
         match all_consuming(parse_command).parse(&l).finish() {
             Ok(cmd) => v.push_back(cmd.1),
-            Err(e) => return Err(e.into()),
+            Err(e) => return Err(ParseError::new(idx + 1, &l, e).into()),
         };
     }
 
+    log::debug!("parsed {} commands", v.len());
     Ok(v)
 }
 
-fn parse_command(i: &str) -> IResult<&str, Command> {
-    alt((parse_noop, parse_addx)).parse(i)
+fn parse_command(i: &str) -> IResult<'_, Command> {
+    context("\"noop\" or \"addx\"", alt((parse_noop, parse_addx))).parse(i)
 }
 
-fn parse_addx(i: &str) -> IResult<&str, Command> {
-    map(preceded(tag("addx "), nom::character::complete::i32), |x| {
-        Command::Addx(x)
-    }).parse(i)
+fn parse_addx(i: &str) -> IResult<'_, Command> {
+    map(
+        preceded(
+            tag("addx "),
+            context("a number", nom::character::complete::i32),
+        ),
+        Command::Addx,
+    )
+    .parse(i)
 }
 
-fn parse_noop(i: &str) -> IResult<&str, Command> {
+fn parse_noop(i: &str) -> IResult<'_, Command> {
     map(tag("noop"), |_| Command::Noop).parse(i)
 }
 
@@ -142,161 +270,7 @@ mod tests {
 
     #[test]
     fn lines_to_result_works() {
-        let s = "addx 15
-addx -11
-addx 6
-addx -3
-addx 5
-addx -1
-addx -8
-addx 13
-addx 4
-noop
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx -35
-addx 1
-addx 24
-addx -19
-addx 1
-addx 16
-addx -11
-noop
-noop
-addx 21
-addx -15
-noop
-noop
-addx -3
-addx 9
-addx 1
-addx -3
-addx 8
-addx 1
-addx 5
-noop
-noop
-noop
-noop
-noop
-addx -36
-noop
-addx 1
-addx 7
-noop
-noop
-noop
-addx 2
-addx 6
-noop
-noop
-noop
-noop
-noop
-addx 1
-noop
-noop
-addx 7
-addx 1
-noop
-addx -13
-addx 13
-addx 7
-noop
-addx 1
-addx -33
-noop
-noop
-noop
-addx 2
-noop
-noop
-noop
-addx 8
-noop
-addx -1
-addx 2
-addx 1
-noop
-addx 17
-addx -9
-addx 1
-addx 1
-addx -3
-addx 11
-noop
-noop
-addx 1
-noop
-addx 1
-noop
-noop
-addx -13
-addx -19
-addx 1
-addx 3
-addx 26
-addx -30
-addx 12
-addx -1
-addx 3
-addx 1
-noop
-noop
-noop
-addx -9
-addx 18
-addx 1
-addx 2
-noop
-noop
-addx 9
-noop
-noop
-noop
-addx -1
-addx 2
-addx -37
-addx 1
-addx 3
-noop
-addx 15
-addx -21
-addx 22
-addx -6
-addx 1
-noop
-addx 2
-addx 1
-noop
-addx -10
-noop
-noop
-addx 20
-addx 1
-addx 2
-addx 2
-addx -6
-addx -11
-noop
-noop
-noop";
-
-        let img = "
-##..##..##..##..##..##..##..##..##..##..
-###...###...###...###...###...###...###.
-####....####....####....####....####....
-#####.....#####.....#####.....#####.....
-######......######......######......####
-#######.......#######.......#######.....
-";
+        let s = EXAMPLE;
 
         let mut reader = std::io::BufReader::new(s.as_bytes());
 
@@ -304,6 +278,71 @@ noop";
 
         assert_eq!(13140, v.iter().sum::<i32>());
 
-        assert_eq!(img, format!("{:?}", crt));
+        assert_eq!(format!("\n{}\n", EXAMPLE_IMAGE), format!("{:?}", crt));
+        assert_eq!(EXAMPLE_IMAGE, crt.to_string());
+    }
+
+    #[test]
+    fn parse_lines_to_commands_reports_location() {
+        let s = "noop\naddx 3\naddx five\n";
+        let mut reader = std::io::BufReader::new(s.as_bytes());
+
+        let Err(MyError::Parser(e)) = parse_lines_to_commands(&mut reader) else {
+            panic!("expected a parse error");
+        };
+
+        assert_eq!((3, 6), (e.line, e.column));
+        assert_eq!("a number", e.expected);
+        assert_eq!("five", e.found());
+    }
+
+    #[test]
+    fn cpu_tick_works() {
+        let commands = [Command::Noop, Command::Addx(3), Command::Addx(-5)];
+        let mut cpu = Cpu::new(commands.into_iter().collect());
+        let mut xs = vec![cpu.x];
+        while cpu.tick().unwrap() {
+            xs.push(cpu.x);
+        }
+        assert_eq!(vec![1, 1, 1, 4, 4, -1], xs);
+        assert_eq!(6, cpu.cycle);
+        assert!(cpu.commands.is_empty());
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        let commands = [Command::Addx(i32::MAX - 1), Command::Addx(1)];
+        let mut cpu = Cpu::new(commands.into_iter().collect());
+        let result = std::iter::repeat_with(|| cpu.tick()).find(|r| !matches!(r, Ok(true)));
+        assert!(matches!(result, Some(Err(MyError::Overflow(4)))));
+
+        let input = "noop\n".repeat(17) + "addx 2147483646\nnoop\nnoop\n";
+        let parsed = Day10::parse(&input).unwrap();
+        assert!(matches!(Day10::part1(&parsed), Err(MyError::Overflow(20))));
+
+        let input = "addx 19999999\n".to_string() + &"noop\n".repeat(98);
+        let parsed = Day10::parse(&input).unwrap();
+        assert!(matches!(
+            Day10::part1(&parsed),
+            Err(MyError::SignalOverflow)
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips() {
+        let mut cpu = Cpu::new(Day10::parse(EXAMPLE).unwrap());
+        for _ in 0..100 {
+            cpu.tick().unwrap();
+        }
+        let json = serde_json::to_string(&cpu).unwrap();
+        let mut loaded: Cpu = serde_json::from_str(&json).unwrap();
+        while cpu.tick().unwrap() {}
+        while loaded.tick().unwrap() {}
+        assert!(cpu.crt == loaded.crt);
+
+        let json = serde_json::to_string(&cpu.crt).unwrap();
+        assert_eq!(serde_json::to_string(EXAMPLE_IMAGE).unwrap(), json);
+        assert!(serde_json::from_str::<Crt>(r##""#.#.""##).is_err());
     }
 }
@@ -0,0 +1,8 @@
+//! Day 11: Monkey in the Middle.
+
+mod mod_day11;
+
+pub use mod_day11::{
+    Day11, EXAMPLE, Item, MONKEY_GETS_BORED, Monkey, MonkeyList, MyError, Operation, ROUNDS_PART1,
+    ROUNDS_PART2, Term, TestDivisibleBy, TestIfFalse, TestIfTrue, parse_all_monkeys,
+};
@@ -1,34 +1,78 @@
+use aoc_core::{Example, ParseError, Solution, parse::IResult};
 use nom::{
+    Finish, Parser,
     branch::alt,
     bytes::complete::tag,
     character::complete::{digit1, one_of, space0, space1},
     combinator::{map, map_parser},
+    error::context,
     multi::separated_list0,
     sequence::{delimited, preceded},
-    Finish, IResult, Parser,
 };
 use std::collections::VecDeque;
 use thiserror::Error;
 
-const MONKEY_GETS_BORED: bool = false;
+pub const MONKEY_GETS_BORED: bool = false;
+pub const ROUNDS_PART1: usize = 20;
+pub const ROUNDS_PART2: usize = 10_000;
+
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Monkey in the Middle";
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE, "10605", "2713310158")];
+    type Parsed = MonkeyList;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Error = MyError;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        let reader = std::io::BufReader::new(input.as_bytes());
+        parse_all_monkeys(reader)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Self::Answer1, Self::Error> {
+        let mut ml = parsed.clone();
+        while ml.round < ROUNDS_PART1 {
+            ml.complete_round_with(true)?;
+        }
+        ml.monkey_business()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Self::Answer2, Self::Error> {
+        let mut ml = parsed.clone();
+        while ml.round < ROUNDS_PART2 {
+            ml.complete_round_with(false)?;
+        }
+        ml.monkey_business()
+    }
+}
 
 #[derive(Error, Debug)]
 pub enum MyError {
-    #[error("parsing error, {0:?}")]
-    Parser(nom::error::ErrorKind),
+    #[error("parsing error, {0}")]
+    Parser(#[from] ParseError),
     #[error(transparent)]
     Io(#[from] std::io::Error),
     #[error("Tried to process item from monkey with no items!")]
     EmptyItems,
-}
-
-impl<T> From<nom::error::Error<T>> for MyError {
-    fn from(err: nom::error::Error<T>) -> Self {
-        Self::Parser(err.code)
-    }
+    #[error("Need at least two monkeys to calculate monkey business")]
+    TooFewMonkeys,
+    #[error("Monkey {0} tests if worry levels are divisible by 0")]
+    ZeroDivisor(u8),
+    #[error("Monkey {0} throws to monkey {1}, which doesn't exist")]
+    NoSuchTarget(u8, u8),
+    #[error("The product of the monkeys' divisors overflows")]
+    DivisorOverflow,
+    #[error("Worry level {0} overflows when inspected")]
+    WorryOverflow(u64),
 }
 
 #[derive(Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MonkeyList {
     pub round: usize,
     pub data: Vec<Monkey>,
@@ -36,9 +80,9 @@ pub struct MonkeyList {
 }
 
 impl MonkeyList {
-    pub fn process_monkey(&mut self, id: usize) -> Result<(), MyError> {
+    pub fn process_monkey(&mut self, id: usize, gets_bored: bool) -> Result<(), MyError> {
         let monkey = &mut self.data[id];
-        let to_send_items = monkey.complete_turn(self.div_product)?;
+        let to_send_items = monkey.complete_turn(self.div_product, gets_bored)?;
 
         for send_item in to_send_items {
             self.data[send_item.0 as usize].items.push_back(send_item.1);
@@ -47,13 +91,36 @@ impl MonkeyList {
     }
 
     pub fn complete_round(&mut self) -> Result<usize, MyError> {
+        self.complete_round_with(MONKEY_GETS_BORED)
+    }
+
+    pub fn complete_round_with(&mut self, gets_bored: bool) -> Result<usize, MyError> {
         for i in 0..self.data.len() {
-            self.process_monkey(i)?;
+            self.process_monkey(i, gets_bored)?;
         }
         self.round += 1;
+        log::trace!("after round {}, {:?}", self.round, self.inspections());
         Ok(self.round)
     }
 
+    pub fn inspections(&self) -> Vec<u64> {
+        self.data.iter().map(|m| m.num_items_inspected).collect()
+    }
+
+    pub fn monkey_business(&self) -> Result<u64, MyError> {
+        let mut v_num_inspected = self.inspections();
+        log::debug!(
+            "after {} rounds monkeys inspected {:?}",
+            self.round,
+            v_num_inspected
+        );
+        if v_num_inspected.len() < 2 {
+            return Err(MyError::TooFewMonkeys);
+        }
+        v_num_inspected.sort_by(|a, b| b.cmp(a));
+        Ok(v_num_inspected[0] * v_num_inspected[1])
+    }
+
     pub fn set_div_product(&mut self) {
         self.div_product = self.data.iter().map(|m| m.div.0).product();
     }
@@ -70,6 +137,7 @@ impl std::fmt::Debug for MonkeyList {
 }
 
 #[derive(Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Monkey {
     pub id: u8,
     pub items: VecDeque<Item>,
@@ -81,21 +149,31 @@ pub struct Monkey {
 }
 
 impl Monkey {
-    pub fn process_one_item(&mut self, div_product: u64) -> Result<(u8, Item), MyError> {
+    pub fn process_one_item(
+        &mut self,
+        div_product: u64,
+        gets_bored: bool,
+    ) -> Result<(u8, Item), MyError> {
         let mut item = self.items.pop_front().ok_or(MyError::EmptyItems)?;
-        item.inspect(&self.op);
-        if MONKEY_GETS_BORED {
-            item.bored_with();
+        item.inspect(&self.op)?;
+        // dividing by 3 doesn't commute with the modulo, so only keep
+        // worry levels small when the monkeys never get bored
+        match gets_bored {
+            true => item.bored_with(),
+            false => item.0 %= div_product,
         };
-        item.0 %= div_product;
         let send_to = item.where_to_throw(&self.div, &self.if_true, &self.if_false);
         Ok((send_to, item))
     }
 
-    pub fn complete_turn(&mut self, div_product: u64) -> Result<Vec<(u8, Item)>, MyError> {
+    pub fn complete_turn(
+        &mut self,
+        div_product: u64,
+        gets_bored: bool,
+    ) -> Result<Vec<(u8, Item)>, MyError> {
         let mut v = Vec::new();
         while !self.items.is_empty() {
-            v.push(self.process_one_item(div_product)?);
+            v.push(self.process_one_item(div_product, gets_bored)?);
             self.num_items_inspected += 1;
         }
         Ok(v)
@@ -114,11 +192,13 @@ impl std::fmt::Debug for Monkey {
 }
 
 #[derive(Clone, PartialEq, PartialOrd)]
-pub struct Item(u64);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Item(pub u64);
 
 impl Item {
-    pub fn inspect(&mut self, op: &Operation) {
-        self.0 = op.eval(self.0);
+    pub fn inspect(&mut self, op: &Operation) -> Result<(), MyError> {
+        self.0 = op.eval(self.0).ok_or(MyError::WorryOverflow(self.0))?;
+        Ok(())
     }
 
     pub fn bored_with(&mut self) {
@@ -126,7 +206,7 @@ impl Item {
     }
 
     fn _test_divisible_by(&self, div: &TestDivisibleBy) -> bool {
-        self.0 % div.0 == 0
+        self.0.is_multiple_of(div.0)
     }
 
     pub fn where_to_throw(
@@ -135,7 +215,7 @@ impl Item {
         if_true: &TestIfTrue,
         if_false: &TestIfFalse,
     ) -> u8 {
-        match self.0 % div.0 == 0 {
+        match self.0.is_multiple_of(div.0) {
             true => if_true.0,
             false => if_false.0,
         }
@@ -150,30 +230,36 @@ impl std::fmt::Debug for Item {
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
-pub struct TestDivisibleBy(u64);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TestDivisibleBy(pub u64);
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
-pub struct TestIfTrue(u8);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TestIfTrue(pub u8);
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
-pub struct TestIfFalse(u8);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TestIfFalse(pub u8);
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Operation {
     Add(Term, Term),
     Multiply(Term, Term),
 }
 
 impl Operation {
-    pub fn eval(&self, old: u64) -> u64 {
+    /// None if the new worry level overflows.
+    pub fn eval(&self, old: u64) -> Option<u64> {
         match self {
-            Operation::Add(a, b) => a.eval(old) + b.eval(old),
-            Operation::Multiply(a, b) => a.eval(old) * b.eval(old),
+            Operation::Add(a, b) => a.eval(old).checked_add(b.eval(old)),
+            Operation::Multiply(a, b) => a.eval(old).checked_mul(b.eval(old)),
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Term {
     Old,
     Constant(u64),
@@ -191,8 +277,10 @@ impl Term {
 pub fn parse_all_monkeys<R: std::io::BufRead>(mut reader: R) -> Result<MonkeyList, MyError> {
     let mut v = Vec::new();
     let mut s = String::new();
+    let mut line_num = 0;
     loop {
-        v.push(parse_monkey(&mut reader)?);
+        v.push(parse_monkey(&mut reader, &mut line_num)?);
+        line_num += 1;
         match reader.read_line(&mut s) {
             Ok(a) => {
                 if a == 0 {
@@ -202,34 +290,51 @@ pub fn parse_all_monkeys<R: std::io::BufRead>(mut reader: R) -> Result<MonkeyLis
             Err(_) => break,
         }
     }
+    validate_monkeys(&v)?;
     let mut ml = MonkeyList {
         round: 0,
         data: v,
         div_product: 0,
     };
     ml.set_div_product();
+    log::debug!(
+        "parsed {} monkeys, product of divisors {}",
+        ml.data.len(),
+        ml.div_product
+    );
     Ok(ml)
 }
 
-fn parse_monkey<R: std::io::BufRead>(reader: &mut R) -> Result<Monkey, MyError> {
-    let mut s = String::new();
-    let _ = reader.read_line(&mut s)?;
-    let id = parse_monkey_id(&s).finish()?.1;
-    s.clear();
-    let _ = reader.read_line(&mut s)?;
-    let items = parse_starting_items(&s).finish()?.1;
-    s.clear();
-    let _ = reader.read_line(&mut s)?;
-    let op = parse_operation(&s).finish()?.1;
-    s.clear();
-    let _ = reader.read_line(&mut s)?;
-    let div = parse_test_divisible_by(&s).finish()?.1;
-    s.clear();
-    let _ = reader.read_line(&mut s)?;
-    let if_true = parse_test_if_true(&s).finish()?.1;
-    s.clear();
-    let _ = reader.read_line(&mut s)?;
-    let if_false = parse_test_if_false(&s).finish()?.1;
+/// Checks each monkey's divisor and targets, so solving can't divide by zero
+/// or throw to a monkey that isn't there.
+fn validate_monkeys(monkeys: &[Monkey]) -> Result<(), MyError> {
+    for monkey in monkeys {
+        if monkey.div.0 == 0 {
+            return Err(MyError::ZeroDivisor(monkey.id));
+        }
+        for target in [monkey.if_true.0, monkey.if_false.0] {
+            if target as usize >= monkeys.len() {
+                return Err(MyError::NoSuchTarget(monkey.id, target));
+            }
+        }
+    }
+    monkeys
+        .iter()
+        .try_fold(1u64, |product, m| product.checked_mul(m.div.0))
+        .ok_or(MyError::DivisorOverflow)?;
+    Ok(())
+}
+
+fn parse_monkey<R: std::io::BufRead>(
+    reader: &mut R,
+    line_num: &mut usize,
+) -> Result<Monkey, MyError> {
+    let id = parse_next_line(reader, line_num, parse_monkey_id)?;
+    let items = parse_next_line(reader, line_num, parse_starting_items)?;
+    let op = parse_next_line(reader, line_num, parse_operation)?;
+    let div = parse_next_line(reader, line_num, parse_test_divisible_by)?;
+    let if_true = parse_next_line(reader, line_num, parse_test_if_true)?;
+    let if_false = parse_next_line(reader, line_num, parse_test_if_false)?;
 
     Ok(Monkey {
         id,
@@ -242,89 +347,119 @@ fn parse_monkey<R: std::io::BufRead>(reader: &mut R) -> Result<Monkey, MyError>
     })
 }
 
-fn parse_monkey_id(i: &str) -> IResult<&str, u8> {
-    delimited(tag("Monkey "), nom::character::complete::u8, tag(":")).parse(i)
+fn parse_next_line<R: std::io::BufRead, O>(
+    reader: &mut R,
+    line_num: &mut usize,
+    parser: fn(&str) -> IResult<'_, O>,
+) -> Result<O, MyError> {
+    let mut s = String::new();
+    let _ = reader.read_line(&mut s)?;
+    *line_num += 1;
+    match parser(&s).finish() {
+        Ok((_, o)) => Ok(o),
+        Err(e) => Err(ParseError::new(*line_num, &s, e).into()),
+    }
+}
+
+fn parse_monkey_id(i: &str) -> IResult<'_, u8> {
+    delimited(
+        context("\"Monkey \"", tag("Monkey ")),
+        context("a monkey id", nom::character::complete::u8),
+        context("\":\"", tag(":")),
+    )
+    .parse(i)
 }
 
-fn parse_starting_items(i: &str) -> IResult<&str, VecDeque<Item>> {
+fn parse_starting_items(i: &str) -> IResult<'_, VecDeque<Item>> {
     preceded(
         space0,
         map(
             preceded(
-                tag("Starting items: "),
+                context("\"Starting items: \"", tag("Starting items: ")),
                 separated_list0(tag(", "), nom::character::complete::u64),
             ),
             |v| v.into_iter().map(Item).collect::<VecDeque<Item>>(),
         ),
-    ).parse(i)
+    )
+    .parse(i)
 }
 
-fn parse_operation(i: &str) -> IResult<&str, Operation> {
+fn parse_operation(i: &str) -> IResult<'_, Operation> {
     preceded(
         space0,
         map(
             preceded(
-                tag("Operation: new = "),
+                context("\"Operation: new = \"", tag("Operation: new = ")),
                 (
                     parse_term,
-                    preceded(space1, one_of("+*")),
+                    preceded(space1, context("\"+\" or \"*\"", one_of("+*"))),
                     preceded(space1, parse_term),
                 ),
             ),
             |x| match x.1 {
                 '+' => Operation::Add(x.0, x.2),
                 '*' => Operation::Multiply(x.0, x.2),
-                _ => {
-                    dbg!("Op char is not + or *");
-                    unreachable!()
-                }
+                _ => unreachable!("Op char is not + or *"),
             },
         ),
-    ).parse(i)
+    )
+    .parse(i)
 }
 
-fn parse_term(i: &str) -> IResult<&str, Term> {
+fn parse_term(i: &str) -> IResult<'_, Term> {
     let p_old = map(tag("old"), |_| Term::Old);
     let p_digit = map_parser(digit1, nom::character::complete::u64);
     let p_const = map(p_digit, Term::Constant);
 
-    alt((p_old, p_const)).parse(i)
+    context("\"old\" or a number", alt((p_old, p_const))).parse(i)
 }
 
-fn parse_test_divisible_by(i: &str) -> IResult<&str, TestDivisibleBy> {
+fn parse_test_divisible_by(i: &str) -> IResult<'_, TestDivisibleBy> {
     preceded(
         space0,
         map(
-            preceded(tag("Test: divisible by "), nom::character::complete::u64),
+            preceded(
+                context("\"Test: divisible by \"", tag("Test: divisible by ")),
+                context("a divisor", nom::character::complete::u64),
+            ),
             TestDivisibleBy,
         ),
-    ).parse(i)
+    )
+    .parse(i)
 }
 
-fn parse_test_if_true(i: &str) -> IResult<&str, TestIfTrue> {
+fn parse_test_if_true(i: &str) -> IResult<'_, TestIfTrue> {
     preceded(
         space0,
         map(
             preceded(
-                tag("If true: throw to monkey "),
-                nom::character::complete::u8,
+                context(
+                    "\"If true: throw to monkey \"",
+                    tag("If true: throw to monkey "),
+                ),
+                context("a monkey id", nom::character::complete::u8),
             ),
             TestIfTrue,
         ),
-    ).parse(i)
+    )
+    .parse(i)
 }
 
-fn parse_test_if_false(i: &str) -> IResult<&str, TestIfFalse> {
+fn parse_test_if_false(i: &str) -> IResult<'_, TestIfFalse> {
     preceded(
         space0,
         map(
             preceded(
-                tag("If false: throw to monkey "),
-                nom::character::complete::u8,
+                context(
+                    "\"If false: throw to monkey \"",
+                    tag("If false: throw to monkey "),
+                ),
+                context("a monkey id", nom::character::complete::u8),
             ),
             TestIfFalse,
         ),
-    ).parse(i)
+    )
+    .parse(i)
 }
 
 #[cfg(test)]
@@ -343,35 +478,7 @@ mod tests {
 
     #[test]
     fn parse_all_monkeys_works() {
-        #[rustfmt::skip]
-        let s = 
-"Monkey 0:
-  Starting items: 79, 98
-  Operation: new = old * 19
-  Test: divisible by 23
-    If true: throw to monkey 2
-    If false: throw to monkey 3
-
-Monkey 1:
-  Starting items: 54, 65, 75, 74
-  Operation: new = old + 6
-  Test: divisible by 19
-    If true: throw to monkey 2
-    If false: throw to monkey 0
-
-Monkey 2:
-  Starting items: 79, 60, 97
-  Operation: new = old * old
-  Test: divisible by 13
-    If true: throw to monkey 1
-    If false: throw to monkey 3
-
-Monkey 3:
-  Starting items: 74
-  Operation: new = old + 3
-  Test: divisible by 17
-    If true: throw to monkey 0
-    If false: throw to monkey 1";
+        let s = EXAMPLE;
 
         let r = vec![
             Monkey {
@@ -419,6 +526,52 @@ Monkey 3:
         assert_eq!(r, v.data);
     }
 
+    #[test]
+    fn parse_all_monkeys_rejects_bad_monkeys() {
+        let parse = |s: String| parse_all_monkeys(std::io::BufReader::new(s.as_bytes()));
+
+        let s = EXAMPLE.replacen("divisible by 23", "divisible by 0", 1);
+        assert!(matches!(parse(s), Err(MyError::ZeroDivisor(0))));
+
+        let s = EXAMPLE.replacen(
+            "If true: throw to monkey 2",
+            "If true: throw to monkey 4",
+            1,
+        );
+        assert!(matches!(parse(s), Err(MyError::NoSuchTarget(0, 4))));
+
+        // throwing to itself only changes the answer
+        let s = EXAMPLE.replacen(
+            "If false: throw to monkey 3",
+            "If false: throw to monkey 0",
+            1,
+        );
+        let mut monkeys = parse(s).unwrap();
+        for _ in 0..20 {
+            monkeys.complete_round().unwrap();
+        }
+        assert!(monkeys.monkey_business().is_ok());
+
+        let s = EXAMPLE.replace("divisible by 23", "divisible by 18446744073709551557");
+        assert!(matches!(parse(s), Err(MyError::DivisorOverflow)));
+    }
+
+    #[test]
+    fn worry_overflow_is_an_error() {
+        let op = Operation::Multiply(Term::Old, Term::Old);
+        assert_eq!(Some(81), op.eval(9));
+        assert_eq!(None, op.eval(1 << 32));
+
+        let s = EXAMPLE.replace("new = old * 19", "new = old * old");
+        let s = s.replace("new = old + 6", "new = old * old");
+        let s = s.replace("new = old + 3", "new = old * old");
+        let parsed = Day11::parse(&s).unwrap();
+        assert!(matches!(
+            Day11::part1(&parsed),
+            Err(MyError::WorryOverflow(_))
+        ));
+    }
+
     #[test]
     fn monkey_list_complete_round_works() {
         let mut ml = MonkeyList {
@@ -518,4 +671,85 @@ Monkey 3:
             assert_eq!(2713310158, monkey_business);
         }
     }
+    #[test]
+    fn monkey_business_works() {
+        #[rustfmt::skip]
+        let s =
+"Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+        let ml = Day11::parse(s).unwrap();
+
+        assert_eq!(10605, Day11::part1(&ml).unwrap());
+        assert_eq!(2713310158, Day11::part2(&ml).unwrap());
+    }
+
+    #[test]
+    fn parse_all_monkeys_reports_location() {
+        let s = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old ^ 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+";
+        let reader = std::io::BufReader::new(s.as_bytes());
+
+        let Err(MyError::Parser(e)) = parse_all_monkeys(reader) else {
+            panic!("expected a parse error");
+        };
+
+        assert_eq!((10, 24), (e.line, e.column));
+        assert_eq!("\"+\" or \"*\"", e.expected);
+        assert_eq!("^", e.found());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips() {
+        let mut ml = Day11::parse(EXAMPLE).unwrap();
+        ml.complete_round().unwrap();
+        let json = serde_json::to_string(&ml).unwrap();
+        assert!(json.starts_with(r#"{"round":1,"data":[{"id":0,"items":[60,71,81,80],"op":{"Multiply":["Old",{"Constant":19}]},"div":23"#));
+        let mut loaded: MonkeyList = serde_json::from_str(&json).unwrap();
+        assert_eq!(ml, loaded);
+
+        // a snapshot carries on where it left off
+        ml.complete_round().unwrap();
+        loaded.complete_round().unwrap();
+        assert_eq!(ml, loaded);
+    }
 }
@@ -41,6 +41,14 @@ impl AppDay12 {
 
 impl eframe::App for AppDay12 {
     fn update(&mut self, ctx: &Context, _frame: &mut eframe::Frame) {
+        self.show(ctx);
+    }
+}
+
+impl AppDay12 {
+    /// Adds the controls, status bar and grid as panels of `ctx`, so the
+    /// visualizer can be hosted inside another app.
+    pub fn show(&mut self, ctx: &Context) {
         // assign grid once it comes in
         if let Ok(f) = self.grid_channel.1.try_recv() {
             self.paused = true;
@@ -156,12 +164,12 @@ impl eframe::App for AppDay12 {
                         return;
                     }
                     let grid = self.grid.as_ref().unwrap();
-                    if let Some(end_coord) = grid.get_end_coord() {
-                        if self.bfs.current.contains(&end_coord) {
-                            self.goal_path = self.bfs.trace_back_path(end_coord).unwrap();
-                            self.paused = true;
-                            return;
-                        }
+                    if let Some(end_coord) = grid.get_end_coord()
+                        && self.bfs.current.contains(&end_coord)
+                    {
+                        self.goal_path = self.bfs.trace_back_path(end_coord).unwrap();
+                        self.paused = true;
+                        return;
                     };
                     match self.b_step_up {
                         true => self.bfs.step_up(grid),
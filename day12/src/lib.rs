@@ -1,4 +1,9 @@
+//! Day 12: Hill Climbing Algorithm.
+
+#[cfg(feature = "app")]
 mod app;
 mod mod_day12;
 
+#[cfg(feature = "app")]
 pub use app::AppDay12;
+pub use mod_day12::{Bfs, Cell, Day12, EXAMPLE, Grid, MyError, fewest_steps, parse_into_grid};
@@ -1,15 +1,36 @@
+use aoc_core::{Example, Solution};
 use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
 use thiserror::Error;
 
-const SMALL_GRID: &str = "Sabqponm\n\
-                            abcryxxl\n\
-                            accszExk\n\
-                            acctuvwj\n\
-                            abdefghi";
-
 const BIG_GRID: &str = include_str!("input.txt");
 
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Hill Climbing Algorithm";
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE, "31", "29")];
+    type Parsed = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = MyError;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        parse_into_grid(input.as_bytes())
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Self::Answer1, Self::Error> {
+        fewest_steps(parsed, false)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Self::Answer2, Self::Error> {
+        fewest_steps(parsed, true)
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum MyError {
     #[error(transparent)]
@@ -20,17 +41,45 @@ pub enum MyError {
     InvalidGridCoordinate((usize, usize)),
     #[error("Couldn't find coordinate in BFS visited coordinates. Coord={0:?}")]
     BFSNotVisitedCoord((usize, usize)),
+    #[error("Grid has no start cell")]
+    NoStartCell,
+    #[error("Grid has no end cell")]
+    NoEndCell,
+    #[error("No path reaches the end cell")]
+    NoPathToEnd,
 }
 
 #[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bfs {
+    #[cfg_attr(feature = "serde", serde(with = "visited_pairs"))]
     pub visited: HashMap<(usize, usize), Option<(usize, usize)>>,
     pub current: HashSet<(usize, usize)>,
     pub num_steps: usize,
 }
 
+/// JSON only has string keys, so the visited map is written as a list of
+/// `[coord, came_from]` pairs.
+#[cfg(feature = "serde")]
+mod visited_pairs {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::HashMap;
+
+    type Visited = HashMap<(usize, usize), Option<(usize, usize)>>;
+
+    pub fn serialize<S: Serializer>(visited: &Visited, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut pairs = visited.iter().collect::<Vec<_>>();
+        pairs.sort();
+        pairs.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Visited, D::Error> {
+        let pairs = Vec::<((usize, usize), Option<(usize, usize)>)>::deserialize(deserializer)?;
+        Ok(pairs.into_iter().collect())
+    }
+}
+
 impl Bfs {
-    #[allow(dead_code)]
     pub fn new() -> Self {
         Bfs::default()
     }
@@ -131,6 +180,7 @@ impl Bfs {
 }
 
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Cell {
     Start,
     End,
@@ -157,6 +207,7 @@ impl std::fmt::Debug for Cell {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Grid {
     pub width: usize,
     pub height: usize,
@@ -165,7 +216,7 @@ pub struct Grid {
 
 impl Default for Grid {
     fn default() -> Self {
-        let s = SMALL_GRID;
+        let s = EXAMPLE;
         let reader = std::io::BufReader::new(s.as_bytes());
         parse_into_grid(reader).unwrap()
     }
@@ -173,7 +224,7 @@ impl Default for Grid {
 
 impl Grid {
     pub fn new_small_grid() -> Self {
-        let s = SMALL_GRID;
+        let s = EXAMPLE;
         let reader = std::io::BufReader::new(s.as_bytes());
         parse_into_grid(reader).unwrap()
     }
@@ -221,7 +272,6 @@ impl Grid {
         Ok(umoves)
     }
 
-    #[allow(dead_code)]
     pub fn get_available_moves_down(
         &self,
         coord: (usize, usize),
@@ -292,7 +342,6 @@ impl Grid {
         Some(&self.data[i])
     }
 
-    #[allow(dead_code)]
     pub fn get_mut_cell_from_coord(&mut self, coord: (usize, usize)) -> Option<&mut Cell> {
         if !self.is_valid_coord(coord) {
             return None;
@@ -382,6 +431,33 @@ impl IntoIterator for Grid {
     }
 }
 
+pub fn fewest_steps(grid: &Grid, from_any_ground: bool) -> Result<usize, MyError> {
+    let end_coord = grid.get_end_coord().ok_or(MyError::NoEndCell)?;
+    if !from_any_ground {
+        grid.get_start_coord().ok_or(MyError::NoStartCell)?;
+    }
+
+    let mut bfs = Bfs::new();
+    loop {
+        match from_any_ground {
+            true => bfs.step_up(grid),
+            false => bfs.step(grid),
+        };
+        log::trace!(
+            "step {}, {} cells on the frontier",
+            bfs.num_steps,
+            bfs.current.len()
+        );
+        if bfs.current.contains(&end_coord) {
+            log::debug!("reached the end in {} steps", bfs.num_steps);
+            return Ok(bfs.num_steps);
+        }
+        if bfs.current.is_empty() {
+            return Err(MyError::NoPathToEnd);
+        }
+    }
+}
+
 pub fn parse_into_grid<R: std::io::BufRead>(mut reader: R) -> Result<Grid, MyError> {
     let mut first_line = String::new();
     reader.read_line(&mut first_line)?;
@@ -407,6 +483,7 @@ pub fn parse_into_grid<R: std::io::BufRead>(mut reader: R) -> Result<Grid, MyErr
         data.extend(v_line);
         height += 1;
     }
+    log::debug!("parsed {} x {} grid", width, height);
     let grid = Grid {
         width,
         height,
@@ -430,19 +507,19 @@ mod tests {
 
     #[test]
     fn parse_into_grid_works() {
-        let s = SMALL_GRID;
+        let s = EXAMPLE;
 
         let reader = std::io::BufReader::new(s.as_bytes());
 
         let g = parse_into_grid(reader).unwrap();
 
-        assert_eq!(s, format!("{:?}", g).trim());
+        assert_eq!(s.trim(), format!("{:?}", g).trim());
     }
 
     #[test]
     fn bfs_works() {
         #[rustfmt::skip]
-        let s = SMALL_GRID;
+        let s = EXAMPLE;
 
         let reader = std::io::BufReader::new(s.as_bytes());
 
@@ -462,7 +539,7 @@ mod tests {
     #[test]
     fn bfs_up_works() {
         #[rustfmt::skip]
-        let s = SMALL_GRID;
+        let s = EXAMPLE;
 
         let reader = std::io::BufReader::new(s.as_bytes());
 
@@ -482,7 +559,7 @@ mod tests {
     #[test]
     fn bfs_down_works() {
         #[rustfmt::skip]
-        let s = SMALL_GRID;
+        let s = EXAMPLE;
 
         let reader = std::io::BufReader::new(s.as_bytes());
 
@@ -511,4 +588,38 @@ mod tests {
 
         assert_eq!(29, &path1.len() - 1);
     }
+    #[test]
+    fn fewest_steps_works() {
+        let grid = Grid::new_small_grid();
+
+        assert_eq!(31, fewest_steps(&grid, false).unwrap());
+        assert_eq!(29, fewest_steps(&grid, true).unwrap());
+
+        let reader = std::io::BufReader::new("Sbz\nzzE".as_bytes());
+        let grid = parse_into_grid(reader).unwrap();
+        assert!(matches!(
+            fewest_steps(&grid, false),
+            Err(MyError::NoPathToEnd)
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips() {
+        let grid = Day12::parse(EXAMPLE).unwrap();
+        let json = serde_json::to_string(&grid).unwrap();
+        assert!(json.starts_with(r#"{"width":8,"height":5,"data":["Start",{"Square":0}"#));
+        let loaded: Grid = serde_json::from_str(&json).unwrap();
+        assert_eq!(format!("{:?}", grid), format!("{:?}", loaded));
+
+        let mut bfs = Bfs::new();
+        bfs.step(&grid);
+        bfs.step(&grid);
+        let json = serde_json::to_string(&bfs).unwrap();
+        assert!(json.starts_with(r#"{"visited":[[[0,0],null],[[0,1],[0,0]],[[1,0],[0,0]]]"#));
+        let loaded: Bfs = serde_json::from_str(&json).unwrap();
+        assert_eq!(bfs.visited, loaded.visited);
+        assert_eq!(bfs.current, loaded.current);
+        assert_eq!(bfs.num_steps, loaded.num_steps);
+    }
 }
@@ -0,0 +1,5 @@
+//! Day 2: Rock Paper Scissors.
+
+mod mod_day2;
+
+pub use mod_day2::{Day2, EXAMPLE, Move, MyError, Outcome, Round, parse_rounds};
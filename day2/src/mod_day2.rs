@@ -0,0 +1,284 @@
+use aoc_core::{Example, Solution};
+use std::io::prelude::*;
+use thiserror::Error;
+
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Rock Paper Scissors";
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE, "15", "12")];
+    type Parsed = Vec<Round>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+    type Error = MyError;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        let mut reader = std::io::BufReader::new(input.as_bytes());
+        parse_rounds(&mut reader)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Self::Answer1, Self::Error> {
+        Ok(parsed.iter().map(|round| round.score_as_moves()).sum())
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Self::Answer2, Self::Error> {
+        Ok(parsed.iter().map(|round| round.score()).sum())
+    }
+}
+
+/// Lines are counted from 0, but shown counting from 1.
+#[derive(Error, Debug)]
+pub enum MyError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("Error parsing input on line {line}", line = .0 + 1)]
+    ParseLine(usize),
+}
+
+pub fn parse_rounds<R: std::io::BufRead>(reader: &mut R) -> Result<Vec<Round>, MyError> {
+    let mut rounds = Vec::new();
+    for (num, line) in reader.lines().enumerate() {
+        let line = line?;
+        let round = match line.parse::<Round>() {
+            Ok(round) => round,
+            _ => return Err(MyError::ParseLine(num)),
+        };
+        log::trace!("round {}: {:?}", num + 1, round);
+        rounds.push(round);
+    }
+    log::debug!("parsed {} rounds", rounds.len());
+    Ok(rounds)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Round {
+    pub theirs: Move,
+    pub outcome: Outcome,
+}
+
+impl std::str::FromStr for Round {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut c = s.chars();
+        let theirs: Move = match c.next() {
+            Some(theirs) => theirs.try_into()?,
+            _ => {
+                return Err(());
+            }
+        };
+        c.next();
+        let outcome: Outcome = match c.next() {
+            Some(outcome) => outcome.try_into()?,
+            _ => {
+                return Err(());
+            }
+        };
+        Ok(Self { theirs, outcome })
+    }
+}
+
+impl Round {
+    pub fn ours(self) -> Move {
+        match self.outcome {
+            Outcome::Loss => self.theirs.winvs(),
+            Outcome::Draw => self.theirs,
+            Outcome::Win => self.theirs.lossvs(),
+        }
+    }
+
+    pub fn score(self) -> isize {
+        (self.outcome as isize) + (self.ours() as isize)
+    }
+
+    // Part 1 reads the second column as our move rather than the outcome
+    pub fn ours_as_move(self) -> Move {
+        match self.outcome {
+            Outcome::Loss => Move::Rock,
+            Outcome::Draw => Move::Paper,
+            Outcome::Win => Move::Scissors,
+        }
+    }
+
+    pub fn score_as_moves(self) -> isize {
+        let ours = self.ours_as_move();
+        let outcome = if ours == self.theirs {
+            Outcome::Draw
+        } else if ours.winvs() == self.theirs {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        };
+        (outcome as isize) + (ours as isize)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Move {
+    Rock = 1,
+    Paper = 2,
+    Scissors = 3,
+}
+
+impl Move {
+    pub fn winvs(self) -> Move {
+        match self {
+            Move::Rock => Move::Scissors,
+            Move::Paper => Move::Rock,
+            Move::Scissors => Move::Paper,
+        }
+    }
+
+    pub fn lossvs(self) -> Move {
+        match self {
+            Move::Rock => Move::Paper,
+            Move::Paper => Move::Scissors,
+            Move::Scissors => Move::Rock,
+        }
+    }
+}
+
+impl TryFrom<char> for Move {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'A' => Ok(Move::Rock),
+            'B' => Ok(Move::Paper),
+            'C' => Ok(Move::Scissors),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Outcome {
+    Loss = 0,
+    Draw = 3,
+    Win = 6,
+}
+
+impl TryFrom<char> for Outcome {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'X' => Ok(Outcome::Loss),
+            'Y' => Ok(Outcome::Draw),
+            'Z' => Ok(Outcome::Win),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rounds_reports_line() {
+        let mut reader = std::io::BufReader::new("A Y\nB\n".as_bytes());
+        let e = parse_rounds(&mut reader).unwrap_err();
+        assert!(matches!(e, MyError::ParseLine(1)));
+        assert_eq!("Error parsing input on line 2", e.to_string());
+    }
+
+    #[test]
+    fn move_from_char_works() {
+        let a = Move::try_from('A').unwrap();
+        assert_eq!(a, Move::Rock);
+
+        let b = Move::try_from('B').unwrap();
+        assert_eq!(b, Move::Paper);
+
+        let c = Move::try_from('C').unwrap();
+        assert_eq!(c, Move::Scissors);
+
+        assert_eq!(Move::try_from(' '), Err(()));
+    }
+
+    #[test]
+    fn outcome_from_char_works() {
+        let x = Outcome::try_from('X').unwrap();
+        assert_eq!(x, Outcome::Loss);
+
+        let y = Outcome::try_from('Y').unwrap();
+        assert_eq!(y, Outcome::Draw);
+
+        let z = Outcome::try_from('Z').unwrap();
+        assert_eq!(z, Outcome::Win);
+
+        assert_eq!(Outcome::try_from(' '), Err(()));
+    }
+
+    #[test]
+    fn round_from_string_works() {
+        let s = "A Y";
+        assert_eq!(
+            s.parse::<Round>().unwrap(),
+            Round {
+                theirs: Move::Rock,
+                outcome: Outcome::Draw,
+            }
+        );
+    }
+
+    #[test]
+    fn game_scoring_works() {
+        let s = "A Y\nB X\nC Z";
+        let mut r = s.lines().map(|l| l.parse::<Round>());
+        let mut total_score = 0;
+
+        let Some(Ok(x)) = r.next() else {
+            return;
+        };
+        assert_eq!(x.ours(), Move::Rock);
+        assert_eq!(x.score(), 4);
+        total_score += x.score();
+
+        let Some(Ok(x)) = r.next() else {
+            return;
+        };
+        assert_eq!(x.ours(), Move::Rock);
+        assert_eq!(x.score(), 1);
+        total_score += x.score();
+
+        let Some(Ok(x)) = r.next() else {
+            return;
+        };
+        assert_eq!(x.ours(), Move::Rock);
+        assert_eq!(x.score(), 7);
+        total_score += x.score();
+
+        assert_eq!(total_score, 12);
+    }
+
+    #[test]
+    fn score_as_moves_works() {
+        let s = EXAMPLE;
+        let mut reader = std::io::BufReader::new(s.as_bytes());
+        let rounds = parse_rounds(&mut reader).unwrap();
+        let scores = rounds
+            .iter()
+            .map(|r| r.score_as_moves())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![8, 1, 6], scores);
+        assert_eq!(15, Day2::part1(&rounds).unwrap());
+        assert_eq!(12, Day2::part2(&rounds).unwrap());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips() {
+        let rounds = Day2::parse(EXAMPLE).unwrap();
+        let json = serde_json::to_string(&rounds).unwrap();
+        assert!(json.starts_with(r#"[{"theirs":"Rock","outcome":"Draw"}"#));
+        assert_eq!(rounds, serde_json::from_str::<Vec<Round>>(&json).unwrap());
+    }
+}
@@ -0,0 +1,5 @@
+//! Day 3: Rucksack Reorganization.
+
+mod mod_day3;
+
+pub use mod_day3::{Day3, EXAMPLE, MyError, Rucksack, get_intersect, priority};
@@ -0,0 +1,139 @@
+use aoc_core::{Example, Solution};
+use std::collections::HashSet;
+use thiserror::Error;
+
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Rucksack Reorganization";
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE, "157", "70")];
+    type Parsed = Vec<Rucksack>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = MyError;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        let rucksacks = input
+            .lines()
+            .enumerate()
+            .map(|(num, line)| {
+                line.chars()
+                    .map(|c| priority(c).ok_or(MyError::InvalidItemOnLine(num, c)))
+                    .collect()
+            })
+            .collect::<Result<Vec<Rucksack>, _>>()?;
+        log::debug!("parsed {} rucksacks", rucksacks.len());
+        Ok(rucksacks)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Self::Answer1, Self::Error> {
+        Ok(part1(parsed))
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Self::Answer2, Self::Error> {
+        Ok(part2(parsed))
+    }
+}
+
+/// The priorities of a rucksack's items, in order.
+pub type Rucksack = Vec<usize>;
+
+/// Lines are counted from 0, but shown counting from 1.
+#[derive(Error, Debug)]
+pub enum MyError {
+    #[error("Not an item character on line {line}, {1}", line = .0 + 1)]
+    InvalidItemOnLine(usize, char),
+}
+
+fn part2(rucksacks: &[Rucksack]) -> usize {
+    rucksacks
+        .chunks(3)
+        .map(|group| {
+            let badge = group
+                .iter()
+                .map(|items| items.iter().copied().collect::<HashSet<usize>>())
+                .reduce(|a, b| a.intersection(&b).copied().collect())
+                .unwrap_or_default();
+            log::trace!("group badge priorities {:?}", badge);
+            badge.iter().sum::<usize>()
+        })
+        .sum()
+}
+
+fn part1(rucksacks: &[Rucksack]) -> usize {
+    let mut total_sum: usize = 0;
+    for (num, items) in rucksacks.iter().enumerate() {
+        let intersect = shared_priorities(items);
+        log::trace!("rucksack {} shares priorities {:?}", num + 1, intersect);
+        total_sum += intersect.iter().sum::<usize>();
+    }
+    log::debug!("total priority {}", total_sum);
+    total_sum
+}
+
+pub fn priority(c: char) -> Option<usize> {
+    match c {
+        'a'..='z' => Some((c as u8 - 97 + 1) as usize),
+        'A'..='Z' => Some((c as u8 - 65 + 27) as usize),
+        _ => None,
+    }
+}
+
+pub fn get_intersect(s: &str) -> HashSet<usize> {
+    let items = s.chars().map(|c| priority(c).unwrap()).collect::<Vec<_>>();
+    shared_priorities(&items)
+}
+
+/// Priorities in both halves of a rucksack, leaving out the middle item of
+/// an odd number.
+fn shared_priorities(items: &[usize]) -> HashSet<usize> {
+    let half_len = items.len() / 2;
+    let comp1: HashSet<usize> = items[..half_len].iter().copied().collect();
+    let comp2: HashSet<usize> = items[items.len() - half_len..].iter().copied().collect();
+    comp1.intersection(&comp2).copied().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn priority_works() {
+        assert_eq!(priority('p'), Some(16));
+    }
+
+    #[test]
+    fn parse_reports_line() {
+        let e = Day3::parse("abc\nab1c").unwrap_err();
+        assert!(matches!(e, MyError::InvalidItemOnLine(1, '1')));
+        assert_eq!("Not an item character on line 2, 1", e.to_string());
+    }
+
+    #[test]
+    fn get_intersect_works() {
+        let s = "vJrwpWtwJgWrhcsFMMfFFhFp";
+        let mut intersect = get_intersect(s);
+        for i in intersect.drain() {
+            assert_eq!(i, 16);
+        }
+
+        assert!(intersect.is_empty());
+    }
+
+    #[test]
+    fn part1_works() {
+        let rucksacks = Day3::parse(EXAMPLE).unwrap();
+        let total_sum = part1(&rucksacks);
+        assert_eq!(total_sum, 157);
+    }
+
+    #[test]
+    fn part2_works() {
+        let rucksacks = Day3::parse(EXAMPLE).unwrap();
+        let total_sum = part2(&rucksacks);
+        assert_eq!(total_sum, 70);
+    }
+}
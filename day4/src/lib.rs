@@ -0,0 +1,8 @@
+//! Day 4: Camp Cleanup.
+
+mod mod_day4;
+
+pub use mod_day4::{
+    Day4, EXAMPLE, MyError, SectionAssignment, check_any_overlap, check_complete_overlap,
+    count_any_overlap, count_complete_overlap, parse_input,
+};
@@ -0,0 +1,207 @@
+use aoc_core::{Example, Solution};
+use itertools::Itertools;
+use std::io::prelude::*;
+use thiserror::Error;
+
+pub type SectionAssignment = (usize, usize, usize, usize);
+
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Camp Cleanup";
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE, "2", "4")];
+    type Parsed = Vec<SectionAssignment>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = MyError;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        let mut reader = std::io::BufReader::new(input.as_bytes());
+        parse_input(&mut reader)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Self::Answer1, Self::Error> {
+        Ok(count_complete_overlap(parsed))
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Self::Answer2, Self::Error> {
+        Ok(count_any_overlap(parsed))
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum MyError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    ParseIntError(#[from] std::num::ParseIntError),
+    #[error("Expected two section ranges, found {0:?}")]
+    SectionCount(String),
+}
+
+pub fn count_any_overlap(parsed: &[SectionAssignment]) -> usize {
+    let count = parsed
+        .iter()
+        .map(|sa| check_any_overlap(*sa) as usize)
+        .sum();
+    log::debug!("{} of {} pairs overlap at all", count, parsed.len());
+    count
+}
+
+pub fn check_any_overlap(sa: SectionAssignment) -> bool {
+    let amin = sa.0;
+    let amax = sa.1;
+    let bmin = sa.2;
+    let bmax = sa.3;
+
+    let amax_lt_bmin = amax < bmin;
+    let bmax_lt_amin = bmax < amin;
+    let amin_gt_bmax = amin > bmax;
+    let bmin_gt_amax = bmin > amax;
+
+    !(amax_lt_bmin | bmax_lt_amin | amin_gt_bmax | bmin_gt_amax)
+}
+
+pub fn count_complete_overlap(parsed: &[SectionAssignment]) -> usize {
+    let count = parsed
+        .iter()
+        .map(|sa| check_complete_overlap(*sa) as usize)
+        .sum();
+    log::debug!("{} of {} pairs overlap completely", count, parsed.len());
+    count
+}
+
+pub fn check_complete_overlap(sa: SectionAssignment) -> bool {
+    let amin = sa.0;
+    let amax = sa.1;
+    let bmin = sa.2;
+    let bmax = sa.3;
+
+    let a_in_b = amin >= bmin && amax <= bmax;
+    let b_in_a = bmin >= amin && bmax <= amax;
+
+    a_in_b | b_in_a
+}
+
+pub fn parse_input<R: std::io::BufRead>(reader: &mut R) -> Result<Vec<SectionAssignment>, MyError> {
+    let assignments = reader
+        .lines()
+        .map(|line| -> Result<(usize, usize, usize, usize), MyError> {
+            let l = line?;
+            let v = l
+                .split(',')
+                .map(|s| {
+                    s.split('-')
+                        .map(|n| n.parse::<usize>())
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .flatten_ok()
+                .collect::<Result<Vec<_>, _>>()?;
+            if v.len() != 4 {
+                return Err(MyError::SectionCount(l));
+            }
+            let t = (v[0], v[1], v[2], v[3]);
+            Ok(t)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    log::debug!("parsed {} section assignment pairs", assignments.len());
+    Ok(assignments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_input_works() {
+        let s = EXAMPLE;
+        let mut reader = std::io::BufReader::new(s.as_bytes());
+        let parsed = parse_input(&mut reader).unwrap();
+        let v: Vec<SectionAssignment> = vec![
+            (2, 4, 6, 8),
+            (2, 3, 4, 5),
+            (5, 7, 7, 9),
+            (2, 8, 3, 7),
+            (6, 6, 4, 6),
+            (2, 6, 4, 8),
+        ];
+        assert_eq!(parsed, v);
+    }
+
+    #[test]
+    fn check_complete_overlap_works() {
+        let v: Vec<SectionAssignment> = vec![
+            (2, 4, 6, 8),
+            (2, 3, 4, 5),
+            (5, 7, 7, 9),
+            (2, 8, 3, 7),
+            (6, 6, 4, 6),
+            (2, 6, 4, 8),
+        ];
+
+        let mut overlaps = v.iter().map(|sa| check_complete_overlap(*sa));
+
+        assert!(!overlaps.next().unwrap());
+        assert!(!overlaps.next().unwrap());
+        assert!(!overlaps.next().unwrap());
+        assert!(overlaps.next().unwrap());
+        assert!(overlaps.next().unwrap());
+        assert!(!overlaps.next().unwrap());
+    }
+
+    #[test]
+    fn count_complete_overlap_works() {
+        let v: Vec<SectionAssignment> = vec![
+            (2, 4, 6, 8),
+            (2, 3, 4, 5),
+            (5, 7, 7, 9),
+            (2, 8, 3, 7),
+            (6, 6, 4, 6),
+            (2, 6, 4, 8),
+        ];
+
+        let count = count_complete_overlap(&v);
+
+        assert_eq!(2, count);
+    }
+
+    #[test]
+    fn check_any_overlap_works() {
+        let v: Vec<SectionAssignment> = vec![
+            (2, 4, 6, 8),
+            (2, 3, 4, 5),
+            (5, 7, 7, 9),
+            (2, 8, 3, 7),
+            (6, 6, 4, 6),
+            (2, 6, 4, 8),
+        ];
+
+        let mut overlaps = v.iter().map(|sa| check_any_overlap(*sa));
+
+        assert!(!overlaps.next().unwrap());
+        assert!(!overlaps.next().unwrap());
+        assert!(overlaps.next().unwrap());
+        assert!(overlaps.next().unwrap());
+        assert!(overlaps.next().unwrap());
+        assert!(overlaps.next().unwrap());
+    }
+
+    #[test]
+    fn count_any_overlap_works() {
+        let v: Vec<SectionAssignment> = vec![
+            (2, 4, 6, 8),
+            (2, 3, 4, 5),
+            (5, 7, 7, 9),
+            (2, 8, 3, 7),
+            (6, 6, 4, 6),
+            (2, 6, 4, 8),
+        ];
+
+        let count = count_any_overlap(&v);
+
+        assert_eq!(4, count);
+    }
+}
@@ -0,0 +1,9 @@
+//! Day 5: Supply Stacks.
+
+mod mod_day5;
+
+pub use mod_day5::{
+    Crate, Day5, EXAMPLE, MoveQtyFromTo, MyError, exec_move_part1, exec_move_part2,
+    exec_moves_part1, exec_moves_part2, parse_crate_all_columns, parse_input, parse_move_all_lines,
+    top_of_crate_columns,
+};
@@ -1,10 +1,42 @@
 extern crate nom;
 
-use nom::{Finish, Parser};
+use aoc_core::{Example, ParseError, Solution, parse::IResult};
+use nom::{Finish, Parser, error::context};
 use thiserror::Error;
 
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Supply Stacks";
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE, "CMZ", "MCD")];
+    type Parsed = (Vec<Vec<Crate>>, Vec<MoveQtyFromTo>);
+    type Answer1 = String;
+    type Answer2 = String;
+    type Error = MyError;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Self::Answer1, Self::Error> {
+        let (mut crate_columns, mut moves) = parsed.clone();
+        exec_moves_part1(&mut crate_columns, &mut moves);
+        Ok(top_of_crate_columns(crate_columns))
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Self::Answer2, Self::Error> {
+        let (mut crate_columns, mut moves) = parsed.clone();
+        exec_moves_part2(&mut crate_columns, &mut moves);
+        Ok(top_of_crate_columns(crate_columns))
+    }
+}
+
 #[derive(Clone, PartialEq, PartialOrd)]
-pub struct Crate(char);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Crate(pub char);
 
 impl std::fmt::Debug for Crate {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -13,32 +45,24 @@ impl std::fmt::Debug for Crate {
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MoveQtyFromTo(pub u8, pub u8, pub u8);
 
 #[derive(Error, Debug)]
 pub enum MyError {
     #[error("transpose_rev error, {0}")]
     TransposeRevErr(String),
-    #[error("parsing error, {0:?}")]
-    ParseErr(nom::error::ErrorKind),
-}
-
-impl<T> From<nom::error::Error<T>> for MyError {
-    fn from(err: nom::error::Error<T>) -> Self {
-        // Get details from the error you want,
-        // or even implement for both T variants.
-        Self::ParseErr(err.code)
-    }
+    #[error("parsing error, {0}")]
+    Parser(#[from] ParseError),
+    #[error("line {0}: no stack {1}, there are {2} stacks")]
+    NoSuchStack(usize, u8, usize),
 }
 
 pub fn exec_moves_part2(crate_columns: &mut [Vec<Crate>], moves: &mut Vec<MoveQtyFromTo>) {
     moves.reverse();
 
     while let Some(m) = moves.pop() {
-        let cc = &mut crate_columns[(m.1 - 1) as usize];
-        let drain_at = std::cmp::max(cc.len() - m.0 as usize, 0) as usize;
-        let mut temp_stack: Vec<_> = cc.drain(drain_at..).collect();
-        crate_columns[(m.2 - 1) as usize].append(&mut temp_stack);
+        exec_move_part2(crate_columns, &m);
     }
 }
 
@@ -46,14 +70,30 @@ pub fn exec_moves_part1(crate_columns: &mut [Vec<Crate>], moves: &mut Vec<MoveQt
     moves.reverse();
 
     while let Some(m) = moves.pop() {
-        let cc = &mut crate_columns[(m.1 - 1) as usize];
-        let drain_at = std::cmp::max(cc.len() - m.0 as usize, 0) as usize;
-        let mut temp_stack: Vec<_> = cc.drain(drain_at..).collect();
-        temp_stack.reverse();
-        crate_columns[(m.2 - 1) as usize].append(&mut temp_stack);
+        exec_move_part1(crate_columns, &m);
     }
 }
 
+/// Moves the crates one at a time, reversing their order.
+pub fn exec_move_part1(crate_columns: &mut [Vec<Crate>], m: &MoveQtyFromTo) {
+    let mut temp_stack = lift_crates(crate_columns, m);
+    temp_stack.reverse();
+    crate_columns[(m.2 - 1) as usize].append(&mut temp_stack);
+}
+
+/// Moves the crates all at once, keeping their order.
+pub fn exec_move_part2(crate_columns: &mut [Vec<Crate>], m: &MoveQtyFromTo) {
+    let mut temp_stack = lift_crates(crate_columns, m);
+    crate_columns[(m.2 - 1) as usize].append(&mut temp_stack);
+}
+
+fn lift_crates(crate_columns: &mut [Vec<Crate>], m: &MoveQtyFromTo) -> Vec<Crate> {
+    log::trace!("move {} from {} to {}", m.0, m.1, m.2);
+    let cc = &mut crate_columns[(m.1 - 1) as usize];
+    let drain_at = cc.len().saturating_sub(m.0 as usize);
+    cc.drain(drain_at..).collect()
+}
+
 pub fn top_of_crate_columns(crate_columns: Vec<Vec<Crate>>) -> String {
     let mut top_c = String::new();
     for v in &crate_columns {
@@ -61,9 +101,31 @@ pub fn top_of_crate_columns(crate_columns: Vec<Vec<Crate>>) -> String {
             top_c.push(maybe_c.0);
         }
     }
+    log::debug!("top crates {}", top_c);
     top_c
 }
 
+pub fn parse_input(buffer: &str) -> Result<(Vec<Vec<Crate>>, Vec<MoveQtyFromTo>), MyError> {
+    let crate_columns = parse_crate_all_columns(buffer)?;
+    let max_vlen = crate_columns.iter().map(|v| v.len()).max().unwrap_or(0);
+    let moves = parse_move_all_lines(buffer, max_vlen + 2)?;
+    for (idx, m) in moves.iter().enumerate() {
+        for stack in [m.1, m.2] {
+            if stack == 0 || stack as usize > crate_columns.len() {
+                let line_num = max_vlen + 2 + idx + 1;
+                return Err(MyError::NoSuchStack(line_num, stack, crate_columns.len()));
+            }
+        }
+    }
+    log::debug!(
+        "parsed {} stacks, tallest {}, and {} moves",
+        crate_columns.len(),
+        max_vlen,
+        moves.len()
+    );
+    Ok((crate_columns, moves))
+}
+
 pub fn parse_move_all_lines(
     buffer: &str,
     skip_lines: usize,
@@ -73,38 +135,36 @@ pub fn parse_move_all_lines(
         if idx < skip_lines {
             continue;
         };
-        match nom::combinator::all_consuming(parse_move_line).parse(line).finish() {
+        match nom::combinator::all_consuming(parse_move_line)
+            .parse(line)
+            .finish()
+        {
             Ok((_rest, move_line)) => move_lines.push(move_line),
-            Err(e) => return Err(e.into()),
+            Err(e) => return Err(ParseError::new(idx + 1, line, e).into()),
         }
     }
     Ok(move_lines)
 }
 
-fn parse_move_line(i: &str) -> nom::IResult<&str, MoveQtyFromTo> {
+fn parse_move_line(i: &str) -> IResult<'_, MoveQtyFromTo> {
     let f_qty = nom::sequence::delimited(
-        nom::bytes::complete::tag("move "),
-        nom::character::complete::digit1,
-        nom::bytes::complete::tag(" "),
+        context("\"move \"", nom::bytes::complete::tag("move ")),
+        context("a crate count", nom::character::complete::digit1),
+        context("\" \"", nom::bytes::complete::tag(" ")),
     );
-    let f_parse = |s: &str| -> Result<u8, nom::error::Error<&str>> {
-        s.parse::<u8>().map_err(move |_| nom::error::Error {
-            input: "",
-            code: nom::error::ErrorKind::Digit,
-        })
-    };
+    let f_parse = |s: &str| s.parse::<u8>();
     let (i_next, n_qty) = nom::combinator::map_res(f_qty, f_parse).parse(i)?;
 
     let f_from = nom::sequence::delimited(
-        nom::bytes::complete::tag("from "),
-        nom::character::complete::digit1,
-        nom::bytes::complete::tag(" "),
+        context("\"from \"", nom::bytes::complete::tag("from ")),
+        context("a stack number", nom::character::complete::digit1),
+        context("\" \"", nom::bytes::complete::tag(" ")),
     );
     let (i_next, n_from) = nom::combinator::map_res(f_from, f_parse).parse(i_next)?;
 
     let f_to = nom::sequence::preceded(
-        nom::bytes::complete::tag("to "),
-        nom::character::complete::digit1,
+        context("\"to \"", nom::bytes::complete::tag("to ")),
+        context("a stack number", nom::character::complete::digit1),
     );
     let (i_next, n_to) = nom::combinator::map_res(f_to, f_parse).parse(i_next)?;
 
@@ -161,12 +221,15 @@ fn transpose_rev<T>(v: Vec<Vec<Option<T>>>) -> Result<Vec<Vec<T>>, MyError> {
 
 fn parse_crate_all_lines(buffer: &str) -> Result<Vec<Vec<Option<Crate>>>, MyError> {
     let mut crate_lines = vec![];
-    for line in buffer.lines() {
-        match nom::combinator::all_consuming(parse_crate_line).parse(line).finish() {
+    for (idx, line) in buffer.lines().enumerate() {
+        match nom::combinator::all_consuming(parse_crate_line)
+            .parse(line)
+            .finish()
+        {
             Ok((_rest, crate_line)) => crate_lines.push(crate_line),
-            Err(e) => match parse_is_stack_numbers_line(e.input) {
+            Err(e) => match parse_is_stack_numbers_line(line) {
                 true => break,
-                false => return Err(e.into()),
+                false => return Err(ParseError::new(idx + 1, line, e).into()),
             },
         }
     }
@@ -174,11 +237,11 @@ fn parse_crate_all_lines(buffer: &str) -> Result<Vec<Vec<Option<Crate>>>, MyErro
 }
 
 fn parse_is_stack_numbers_line(i: &str) -> bool {
-    let result: nom::IResult<&str, &str> = nom::bytes::complete::tag(" 1   2")(i);
+    let result: IResult<'_, &str> = nom::bytes::complete::tag(" 1   2")(i);
     result.is_ok()
 }
 
-fn parse_crate_line(i: &str) -> nom::IResult<&str, Vec<Option<Crate>>> {
+fn parse_crate_line(i: &str) -> IResult<'_, Vec<Option<Crate>>> {
     let (mut i, c) = parse_crate_or_hole(i)?;
     let mut v = vec![c];
 
@@ -186,7 +249,8 @@ fn parse_crate_line(i: &str) -> nom::IResult<&str, Vec<Option<Crate>>> {
         let (next_i, maybe_c) = nom::combinator::opt(nom::sequence::preceded(
             nom::bytes::complete::tag(" "),
             parse_crate_or_hole,
-        )).parse(i)?;
+        ))
+        .parse(i)?;
         match maybe_c {
             Some(c) => v.push(c),
             None => break,
@@ -197,30 +261,27 @@ fn parse_crate_line(i: &str) -> nom::IResult<&str, Vec<Option<Crate>>> {
     Ok((i, v))
 }
 
-fn parse_crate_or_hole(i: &str) -> nom::IResult<&str, Option<Crate>> {
-    nom::branch::alt((
-        nom::combinator::map(parse_crate, Some),
-        nom::combinator::map(parse_hole, |_| None),
-    )).parse(i)
+fn parse_crate_or_hole(i: &str) -> IResult<'_, Option<Crate>> {
+    context(
+        "a crate like \"[A]\" or a gap",
+        nom::branch::alt((
+            nom::combinator::map(parse_crate, Some),
+            nom::combinator::map(parse_hole, |_| None),
+        )),
+    )
+    .parse(i)
 }
 
-fn parse_crate(i: &str) -> nom::IResult<&str, Crate> {
-    let first_char = |s: &str| -> Result<Crate, nom::error::Error<&str>> {
-        let c = s.chars().next().ok_or(nom::error::Error {
-            input: "",
-            code: nom::error::ErrorKind::MapRes,
-        })?;
-        Ok(Crate(c))
-    };
+fn parse_crate(i: &str) -> IResult<'_, Crate> {
     let f = nom::sequence::delimited(
         nom::bytes::complete::tag("["),
-        nom::bytes::complete::take(1_usize),
+        nom::character::complete::anychar,
         nom::bytes::complete::tag("]"),
     );
-    nom::combinator::map_res(f, first_char).parse(i)
+    nom::combinator::map(f, Crate).parse(i)
 }
 
-fn parse_hole(i: &str) -> nom::IResult<&str, ()> {
+fn parse_hole(i: &str) -> IResult<'_, ()> {
     nom::combinator::map(nom::bytes::complete::tag("   "), drop).parse(i)
 }
 
@@ -232,7 +293,7 @@ mod tests {
     fn parse_crate_works() {
         let s = "[D]";
         let result = parse_crate(s);
-        let crate_char = result.unwrap().1 .0;
+        let crate_char = result.unwrap().1.0;
         assert_eq!('D', crate_char);
     }
 
@@ -360,19 +421,38 @@ mod tests {
     }
 
     #[test]
-    fn exec_moves_part1_works() {
+    fn parse_move_all_lines_reports_location() {
         let s = "\
-         \x20   [D]    \n\
-            [N] [C]    \n\
-            [Z] [M] [P]\n \
-            1   2   3 \n\
+        move 2 from 8 to 1\n\
+        move 4 frm 9 to 8\n\
+        ";
+
+        let Err(MyError::Parser(e)) = parse_move_all_lines(s, 0) else {
+            panic!("expected a parse error");
+        };
+
+        assert_eq!((2, 8), (e.line, e.column));
+        assert_eq!("\"from \"", e.expected);
+        assert_eq!("frm", e.found());
+    }
+
+    #[test]
+    fn parse_input_rejects_missing_stack() {
+        let s = "\
+            [A] [B]\n \
+            1   2 \n\
             \n\
-            move 1 from 2 to 1\n\
-            move 3 from 1 to 3\n\
-            move 2 from 2 to 1\n\
             move 1 from 1 to 2\n\
+            move 1 from 2 to 3\n\
             ";
 
+        assert!(matches!(parse_input(s), Err(MyError::NoSuchStack(5, 3, 2))));
+    }
+
+    #[test]
+    fn exec_moves_part1_works() {
+        let s = EXAMPLE;
+
         let mut crate_columns = parse_crate_all_columns(s).unwrap();
         let max_vlen = crate_columns.iter().map(|v| v.len()).max().unwrap();
         let mut moves = parse_move_all_lines(s, max_vlen + 2).unwrap();
@@ -386,17 +466,7 @@ mod tests {
 
     #[test]
     fn exec_moves_part2_works() {
-        let s = "\
-         \x20   [D]    \n\
-            [N] [C]    \n\
-            [Z] [M] [P]\n \
-            1   2   3 \n\
-            \n\
-            move 1 from 2 to 1\n\
-            move 3 from 1 to 3\n\
-            move 2 from 2 to 1\n\
-            move 1 from 1 to 2\n\
-            ";
+        let s = EXAMPLE;
 
         let mut crate_columns = parse_crate_all_columns(s).unwrap();
         let max_vlen = crate_columns.iter().map(|v| v.len()).max().unwrap();
@@ -408,4 +478,13 @@ mod tests {
 
         assert_eq!(top_c, "MCD");
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips() {
+        let parsed = Day5::parse(EXAMPLE).unwrap();
+        let json = serde_json::to_string(&parsed).unwrap();
+        assert!(json.starts_with(r#"[[["Z","N"],["M","C","D"],["P"]],[[1,2,1]"#));
+        assert_eq!(parsed, serde_json::from_str(&json).unwrap());
+    }
 }
@@ -0,0 +1,8 @@
+//! Day 6: Tuning Trouble.
+
+mod mod_day6;
+
+pub use mod_day6::{
+    Day6, EXAMPLE, MyError, START_OF_MESSAGE_SIZE, START_OF_PACKET_SIZE, find_marker_idx,
+    find_message_idx, find_unique_window_idx,
+};
@@ -0,0 +1,208 @@
+use aoc_core::{Example, Solution};
+use thiserror::Error;
+
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Tuning Trouble";
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE, "7", "19"),
+        Example::new("bvwbjplbgvbhsrlpgdmjqwftvncz", "5", "23"),
+        Example::new("nppdvjthqldpwncqszvftbrmjlhg", "6", "23"),
+        Example::new("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", "10", "29"),
+        Example::new("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", "11", "26"),
+    ];
+    type Parsed = Vec<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = MyError;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        let datastream = input.trim_end();
+        if let Some(c) = datastream.chars().find(|c| !c.is_ascii_lowercase()) {
+            return Err(MyError::NotLowercaseChar(c));
+        }
+        Ok(datastream.as_bytes().to_vec())
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Self::Answer1, Self::Error> {
+        find_marker_idx(&mut parsed.as_slice())?.ok_or(MyError::NoMarker(START_OF_PACKET_SIZE))
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Self::Answer2, Self::Error> {
+        find_message_idx(&mut parsed.as_slice())?.ok_or(MyError::NoMarker(START_OF_MESSAGE_SIZE))
+    }
+}
+
+pub const START_OF_PACKET_SIZE: usize = 4;
+pub const START_OF_MESSAGE_SIZE: usize = 14;
+
+#[derive(Error, Debug)]
+pub enum MyError {
+    #[error("Not a lowercase character, {0}")]
+    NotLowercaseChar(char),
+    #[error("Not enough data for a marker size, {0}")]
+    NotEnoughData(usize),
+    #[error("No marker of size {0} found")]
+    NoMarker(usize),
+}
+
+pub fn find_marker_idx<R: std::io::BufRead>(reader: &mut R) -> Result<Option<usize>, MyError> {
+    find_unique_window_idx::<START_OF_PACKET_SIZE, R>(reader)
+}
+
+pub fn find_message_idx<R: std::io::BufRead>(reader: &mut R) -> Result<Option<usize>, MyError> {
+    find_unique_window_idx::<START_OF_MESSAGE_SIZE, R>(reader)
+}
+
+pub fn find_unique_window_idx<const MARKER_SIZE: usize, R: std::io::BufRead>(
+    reader: &mut R,
+) -> Result<Option<usize>, MyError> {
+    let mut marker_idx: usize = MARKER_SIZE;
+    let mut n_buf = [0_u8; MARKER_SIZE];
+
+    match reader.read_exact(&mut n_buf) {
+        Ok(_) => {}
+        Err(_) => return Err(MyError::NotEnoughData(MARKER_SIZE)),
+    };
+
+    match unique_chars(&n_buf) {
+        Ok(b) => {
+            if b {
+                log::debug!("marker of size {} ends at {}", MARKER_SIZE, marker_idx);
+                return Ok(Some(marker_idx));
+            }
+        }
+        Err(e) => return Err(e),
+    }
+
+    let mut next_n = [0_u8; 1];
+    while reader.read_exact(&mut next_n).is_ok() {
+        marker_idx += 1;
+        match next_n[0].is_ascii_lowercase() {
+            true => n_buf[(marker_idx - 1) % MARKER_SIZE] = next_n[0],
+            false => return Err(MyError::NotLowercaseChar(next_n[0] as char)),
+        };
+        match unique_chars(&n_buf) {
+            Ok(b) => {
+                if b {
+                    log::debug!("marker of size {} ends at {}", MARKER_SIZE, marker_idx);
+                    return Ok(Some(marker_idx));
+                }
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    log::debug!("no marker of size {} in {} chars", MARKER_SIZE, marker_idx);
+    Ok(None)
+}
+
+fn unique_chars(n_buf: &[u8]) -> Result<bool, MyError> {
+    let count = n_buf.len();
+    let mut bs: u32 = 0b_0;
+    for n in n_buf {
+        bs |= u8_to_u32_bitset(*n)?;
+    }
+    Ok(bs.count_ones() as usize == count)
+}
+
+fn u8_to_u32_bitset(n: u8) -> Result<u32, MyError> {
+    if !n.is_ascii_lowercase() {
+        return Err(MyError::NotLowercaseChar(n as char));
+    }
+    Ok(1 << (n - b'a'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn u8_to_u32_bitset_works() {
+        assert_eq!(u8_to_u32_bitset(b'a').unwrap(), 0b_1);
+        assert_eq!(u8_to_u32_bitset(b'b').unwrap(), 0b_10);
+        assert_eq!(u8_to_u32_bitset(b'c').unwrap(), 0b_100);
+        assert_eq!(
+            u8_to_u32_bitset(b'z').unwrap(),
+            0b_10000000000000000000000000
+        );
+        assert!(matches!(
+            u8_to_u32_bitset(b'A'),
+            Err(MyError::NotLowercaseChar('A'))
+        ));
+        assert!(matches!(
+            u8_to_u32_bitset(b'0'),
+            Err(MyError::NotLowercaseChar('0'))
+        ));
+        assert!(matches!(
+            u8_to_u32_bitset(255_u8),
+            Err(MyError::NotLowercaseChar(_))
+        ));
+    }
+
+    #[test]
+    fn unique_chars_works() {
+        assert!(unique_chars(b"abc").unwrap());
+        assert!(matches!(
+            unique_chars(b"AAA"),
+            Err(MyError::NotLowercaseChar('A'))
+        ));
+        assert!(matches!(
+            unique_chars(b"000"),
+            Err(MyError::NotLowercaseChar('0'))
+        ));
+    }
+
+    #[test]
+    fn find_marker_idx_works() {
+        let s = "mjqjpqmgbljsphdztnvjfqwrcgsmlb".as_bytes();
+        let mut reader = std::io::BufReader::new(s);
+        assert_eq!(Some(7), find_marker_idx(&mut reader).unwrap());
+
+        let s = "bvwbjplbgvbhsrlpgdmjqwftvncz".as_bytes();
+        let mut reader = std::io::BufReader::new(s);
+        assert_eq!(Some(5), find_marker_idx(&mut reader).unwrap());
+
+        let s = "nppdvjthqldpwncqszvftbrmjlhg".as_bytes();
+        let mut reader = std::io::BufReader::new(s);
+        assert_eq!(Some(6), find_marker_idx(&mut reader).unwrap());
+
+        let s = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".as_bytes();
+        let mut reader = std::io::BufReader::new(s);
+        assert_eq!(Some(10), find_marker_idx(&mut reader).unwrap());
+
+        let s = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".as_bytes();
+        let mut reader = std::io::BufReader::new(s);
+        assert_eq!(Some(11), find_marker_idx(&mut reader).unwrap());
+
+        let s = "aaaaaaaaaaaaaaaaaaaaaaaaaaa".as_bytes();
+        let mut reader = std::io::BufReader::new(s);
+        assert_eq!(None, find_marker_idx(&mut reader).unwrap());
+
+        let s = "ab".as_bytes();
+        let mut reader = std::io::BufReader::new(s);
+        assert!(matches!(
+            find_marker_idx(&mut reader),
+            Err(MyError::NotEnoughData(_))
+        ));
+
+        let s = "AAAAAAAbcdef".as_bytes();
+        let mut reader = std::io::BufReader::new(s);
+        assert!(matches!(
+            find_marker_idx(&mut reader),
+            Err(MyError::NotLowercaseChar(_))
+        ));
+    }
+    #[test]
+    fn find_message_idx_works() {
+        for example in Day6::EXAMPLES {
+            let mut reader = std::io::BufReader::new(example.input.as_bytes());
+            let idx = find_message_idx(&mut reader).unwrap().unwrap();
+            assert_eq!(example.part2, Some(idx.to_string().as_str()));
+        }
+    }
+}
@@ -0,0 +1,8 @@
+//! Day 7: No Space Left On Device.
+
+mod mod_day7;
+
+pub use mod_day7::{
+    Command, Day7, EXAMPLE, Entry, FsEntry, Line, MyError, all_lines_into_tree, dir_sizes,
+    parse_all_lines, part2, sum_dir_sizes_part1,
+};
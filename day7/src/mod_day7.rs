@@ -1,19 +1,74 @@
+use aoc_core::{Example, ParseError, Solution, parse::IResult};
 use nom::{
+    Finish, Parser,
     branch::alt,
     bytes::complete::{tag, take_while1},
     combinator::{all_consuming, map},
+    error::context,
     sequence::{preceded, separated_pair},
-    Finish, IResult, Parser,
 };
 use std::io::prelude::*;
 use thiserror::Error;
 use typed_path::{Utf8PathBuf, Utf8UnixEncoding};
 
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "No Space Left On Device";
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE, "95437", "24933642")];
+    type Parsed = Vec<(Utf8PathBuf<Utf8UnixEncoding>, u64)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Error = MyError;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        let mut reader = std::io::BufReader::new(input.as_bytes());
+        let lines = parse_all_lines(&mut reader)?;
+        let tree = all_lines_into_tree(&lines)?;
+        log::debug!("parsed {} lines into {} entries", lines.len(), tree.count());
+        dir_sizes(&tree)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Self::Answer1, Self::Error> {
+        Ok(sum_dir_sizes_part1(parsed))
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Self::Answer2, Self::Error> {
+        part2(parsed).ok_or(MyError::NoDirToDelete)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FsEntry {
-    path: Utf8PathBuf<Utf8UnixEncoding>,
-    size: u64,
-    fullpath: Utf8PathBuf<Utf8UnixEncoding>,
+    #[cfg_attr(feature = "serde", serde(with = "unix_path"))]
+    pub path: Utf8PathBuf<Utf8UnixEncoding>,
+    pub size: u64,
+    #[cfg_attr(feature = "serde", serde(with = "unix_path"))]
+    pub fullpath: Utf8PathBuf<Utf8UnixEncoding>,
+}
+
+/// typed-path has no serde support, so paths are written as strings.
+#[cfg(feature = "serde")]
+mod unix_path {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use typed_path::{Utf8PathBuf, Utf8UnixEncoding};
+
+    pub fn serialize<S: Serializer>(
+        path: &Utf8PathBuf<Utf8UnixEncoding>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(path.as_str())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Utf8PathBuf<Utf8UnixEncoding>, D::Error> {
+        Ok(String::deserialize(deserializer)?.into())
+    }
 }
 
 trait ArenaFsEntry {
@@ -39,49 +94,48 @@ impl ArenaFsEntry for indextree::Arena<FsEntry> {
 
 #[derive(Error, Debug)]
 pub enum MyError {
-    #[error("parsing error, {0:?}")]
-    Parser(nom::error::ErrorKind),
+    #[error("parsing error, {0}")]
+    Parser(#[from] ParseError),
     #[error(transparent)]
     Io(#[from] std::io::Error),
     #[error("IndexTree error, {0:?}")]
     IndexTree(String),
-}
-
-impl<T> From<nom::error::Error<T>> for MyError {
-    fn from(err: nom::error::Error<T>) -> Self {
-        // Get details from the error you want,
-        // or even implement for both T variants.
-        Self::Parser(err.code)
-    }
+    #[error("No directory is large enough to free the space needed")]
+    NoDirToDelete,
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Line {
     Command(Command),
     Entry(Entry),
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Command {
     Ls,
-    Cd(Utf8PathBuf<Utf8UnixEncoding>),
+    Cd(#[cfg_attr(feature = "serde", serde(with = "unix_path"))] Utf8PathBuf<Utf8UnixEncoding>),
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Entry {
-    Dir(Utf8PathBuf<Utf8UnixEncoding>),
-    File(u64, Utf8PathBuf<Utf8UnixEncoding>),
+    Dir(#[cfg_attr(feature = "serde", serde(with = "unix_path"))] Utf8PathBuf<Utf8UnixEncoding>),
+    File(
+        u64,
+        #[cfg_attr(feature = "serde", serde(with = "unix_path"))] Utf8PathBuf<Utf8UnixEncoding>,
+    ),
 }
 
 pub fn part2(v: &[(Utf8PathBuf<Utf8UnixEncoding>, u64)]) -> Option<u64> {
     const TOTAL_AVAIL: u64 = 70000000;
     const NEED_FREE: u64 = 30000000;
 
-    let Some(root_size) = v.iter().map(|d| d.1).max() else {
-        return None;
-    };
+    let root_size = v.iter().map(|d| d.1).max()?;
 
-    let goal_free = std::cmp::max(NEED_FREE - (TOTAL_AVAIL - root_size), 0);
+    let goal_free = NEED_FREE.saturating_sub(TOTAL_AVAIL.saturating_sub(root_size));
+    log::debug!("root uses {}, need to free {}", root_size, goal_free);
 
     v.iter()
         .filter_map(|d| if d.1 >= goal_free { Some(d.1) } else { None })
@@ -115,9 +169,11 @@ pub fn dir_sizes(
                 .filter_map(|d_id| arena.get(d_id))
                 .map(|d_node: &indextree::Node<FsEntry>| d_node.get().size)
                 .sum::<u64>();
+            log::trace!("{} holds {}", fs.fullpath, sum);
             v.push((fs.fullpath.clone(), sum));
         }
     }
+    log::debug!("sized {} directories", v.len());
     Ok(v)
 }
 
@@ -145,7 +201,7 @@ pub fn all_lines_into_tree(lines: &[Line]) -> Result<indextree::Arena<FsEntry>,
                             None => {
                                 return Err(MyError::IndexTree(
                                     "Unable to get current nodes parent".into(),
-                                ))
+                                ));
                             }
                         };
                     }
@@ -218,56 +274,70 @@ pub fn all_lines_into_tree(lines: &[Line]) -> Result<indextree::Arena<FsEntry>,
 pub fn parse_all_lines<R: std::io::BufRead>(reader: &mut R) -> Result<Vec<Line>, MyError> {
     reader
         .lines()
-        .map(|r_line| match r_line {
+        .enumerate()
+        .map(|(idx, r_line)| match r_line {
             Ok(line) => match all_consuming(parse_line).parse(&line).finish() {
                 Ok(o) => Ok(o.1),
-                Err(e) => Err(MyError::from(e)),
+                Err(e) => Err(ParseError::new(idx + 1, &line, e).into()),
             },
             Err(e) => Err(MyError::from(e)),
         })
         .collect::<Result<Vec<_>, _>>()
 }
 
-fn parse_line(i: &str) -> IResult<&str, Line> {
-    alt((
-        map(parse_command, Line::Command),
-        map(parse_entry, Line::Entry),
-    )).parse(i)
+fn parse_line(i: &str) -> IResult<'_, Line> {
+    context(
+        "a command, directory or file",
+        alt((
+            map(parse_command, Line::Command),
+            map(parse_entry, Line::Entry),
+        )),
+    )
+    .parse(i)
 }
 
-fn parse_entry(i: &str) -> IResult<&str, Entry> {
+fn parse_entry(i: &str) -> IResult<'_, Entry> {
     alt((parse_dir, parse_file)).parse(i)
 }
 
-fn parse_file(i: &str) -> IResult<&str, Entry> {
+fn parse_file(i: &str) -> IResult<'_, Entry> {
     map(
-        separated_pair(nom::character::complete::u64, tag(" "), parse_path),
+        separated_pair(
+            context("a file size", nom::character::complete::u64),
+            context("\" \"", tag(" ")),
+            parse_path,
+        ),
         |(size, path)| Entry::File(size, path),
-    ).parse(i)
+    )
+    .parse(i)
 }
 
-fn parse_dir(i: &str) -> IResult<&str, Entry> {
+fn parse_dir(i: &str) -> IResult<'_, Entry> {
     map(preceded(tag("dir "), parse_path), Entry::Dir).parse(i)
 }
 
-fn parse_command(i: &str) -> IResult<&str, Command> {
+fn parse_command(i: &str) -> IResult<'_, Command> {
     let (i, _) = tag("$ ")(i)?;
-    alt((parse_ls, parse_cd)).parse(i)
+    context("\"ls\" or \"cd\"", alt((parse_ls, parse_cd))).parse(i)
 }
 
-fn parse_ls(i: &str) -> IResult<&str, Command> {
+fn parse_ls(i: &str) -> IResult<'_, Command> {
     map(tag("ls"), |_| Command::Ls).parse(i)
 }
 
-fn parse_cd(i: &str) -> IResult<&str, Command> {
+fn parse_cd(i: &str) -> IResult<'_, Command> {
     map(preceded(tag("cd "), parse_path), Command::Cd).parse(i)
 }
 
-fn parse_path(i: &str) -> IResult<&str, Utf8PathBuf<Utf8UnixEncoding>> {
+fn parse_path(i: &str) -> IResult<'_, Utf8PathBuf<Utf8UnixEncoding>> {
     map(
-        take_while1(|c: char| "abcdefghijklmnopqrstuvwxyz./".contains(c)),
+        context(
+            "a path",
+            take_while1(|c: char| "abcdefghijklmnopqrstuvwxyz./".contains(c)),
+        ),
         Into::into,
-    ).parse(i)
+    )
+    .parse(i)
 }
 
 #[cfg(test)]
@@ -352,6 +422,28 @@ mod tests {
         assert_eq!(r, result.unwrap());
     }
 
+    #[test]
+    fn parse_all_lines_reports_location() {
+        let s = "\
+            $ cd /\n\
+            $ ls\n\
+            12x b.txt\n\
+            ";
+        let mut reader = std::io::BufReader::new(s.as_bytes());
+        let Err(MyError::Parser(e)) = parse_all_lines(&mut reader) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((3, 3), (e.line, e.column));
+        assert_eq!("\" \"", e.expected);
+
+        let mut reader = std::io::BufReader::new("$ cd /\n$ rm b.txt".as_bytes());
+        let Err(MyError::Parser(e)) = parse_all_lines(&mut reader) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((2, 3), (e.line, e.column));
+        assert_eq!("\"ls\" or \"cd\"", e.expected);
+    }
+
     #[test]
     fn all_lines_into_tree_works() {
         let lines = vec![
@@ -367,7 +459,6 @@ mod tests {
 
         let result = all_lines_into_tree(&lines);
         assert!(result.is_ok());
-        dbg!(result.unwrap());
     }
 
     #[test]
@@ -395,31 +486,7 @@ mod tests {
 
     #[test]
     fn sum_dir_sizes_part1_works() {
-        let s = "\
-$ cd /
-$ ls
-dir a
-14848514 b.txt
-8504156 c.dat
-dir d
-$ cd a
-$ ls
-dir e
-29116 f
-2557 g
-62596 h.lst
-$ cd e
-$ ls
-584 i
-$ cd ..
-$ cd ..
-$ cd d
-$ ls
-4060174 j
-8033020 d.log
-5626152 d.ext
-7214296 k\
-";
+        let s = EXAMPLE;
         let mut reader = std::io::BufReader::new(s.as_bytes());
         let lines = parse_all_lines(&mut reader).unwrap();
         let tree = all_lines_into_tree(&lines).unwrap();
@@ -430,31 +497,7 @@ $ ls
 
     #[test]
     fn part2_works() {
-        let s = "\
-$ cd /
-$ ls
-dir a
-14848514 b.txt
-8504156 c.dat
-dir d
-$ cd a
-$ ls
-dir e
-29116 f
-2557 g
-62596 h.lst
-$ cd e
-$ ls
-584 i
-$ cd ..
-$ cd ..
-$ cd d
-$ ls
-4060174 j
-8033020 d.log
-5626152 d.ext
-7214296 k\
-";
+        let s = EXAMPLE;
         let mut reader = std::io::BufReader::new(s.as_bytes());
         let lines = parse_all_lines(&mut reader).unwrap();
         let tree = all_lines_into_tree(&lines).unwrap();
@@ -462,4 +505,26 @@ $ ls
         let sum_part2 = part2(&dir_sizes).unwrap();
         assert_eq!(24933642, sum_part2);
     }
+
+    #[test]
+    fn part2_with_plenty_of_space_works() {
+        let dir_sizes = [("/".into(), 2000), ("/a".into(), 500)];
+        assert_eq!(Some(500), part2(&dir_sizes));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips() {
+        let lines = parse_all_lines(&mut EXAMPLE.as_bytes()).unwrap();
+        let json = serde_json::to_string(&lines).unwrap();
+        assert!(
+            json.starts_with(r#"[{"Command":{"Cd":"/"}},{"Command":"Ls"},{"Entry":{"Dir":"a"}}"#)
+        );
+        assert_eq!(lines, serde_json::from_str::<Vec<Line>>(&json).unwrap());
+
+        let tree = all_lines_into_tree(&lines).unwrap();
+        let json = serde_json::to_string(&tree).unwrap();
+        let loaded: indextree::Arena<FsEntry> = serde_json::from_str(&json).unwrap();
+        assert_eq!(dir_sizes(&tree).unwrap(), dir_sizes(&loaded).unwrap());
+    }
 }
@@ -0,0 +1,7 @@
+//! Day 8: Treetop Tree House.
+
+mod mod_day8;
+
+pub use mod_day8::{
+    Day8, EXAMPLE, MyError, highest_score, read_into_matrix, visible_any_side, visible_trees,
+};
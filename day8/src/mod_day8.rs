@@ -1,7 +1,38 @@
+use aoc_core::{Example, Solution};
 use ndarray::s;
 use std::{collections::HashSet, io::prelude::*};
 use thiserror::Error;
 
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Treetop Tree House";
+    const EXAMPLES: &'static [Example] = &[Example::new(EXAMPLE, "21", "8")];
+    type Parsed = ndarray::Array2<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = MyError;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        if input.trim().is_empty() {
+            return Err(MyError::EmptyInput);
+        }
+        let mut reader = std::io::BufReader::new(input.as_bytes());
+        read_into_matrix(&mut reader)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Self::Answer1, Self::Error> {
+        Ok(visible_trees(parsed).len())
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Self::Answer2, Self::Error> {
+        Ok(highest_score(parsed))
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum MyError {
     #[error("error parsing row to u8, row = {0}")]
@@ -10,9 +41,11 @@ pub enum MyError {
     Io(#[from] std::io::Error),
     #[error(transparent)]
     Ndarray(#[from] ndarray::ShapeError),
+    #[error("No rows of trees in input")]
+    EmptyInput,
 }
 
-pub fn highest_score(matrix: ndarray::Array2<u8>) -> usize {
+pub fn highest_score(matrix: &ndarray::Array2<u8>) -> usize {
     let mut highest_score: usize = 0;
 
     for (idx, el) in matrix.indexed_iter() {
@@ -81,6 +114,7 @@ pub fn highest_score(matrix: ndarray::Array2<u8>) -> usize {
         };
     }
 
+    log::debug!("highest scenic score {}", highest_score);
     highest_score
 }
 
@@ -98,6 +132,9 @@ pub fn read_into_matrix<R: std::io::BufRead>(
 
     let mut row_count: usize = 1;
     let col_count: usize = data.len();
+    if col_count == 0 {
+        return Err(MyError::EmptyInput);
+    }
 
     data.reserve_exact(col_count * (col_count - 1));
 
@@ -118,10 +155,29 @@ pub fn read_into_matrix<R: std::io::BufRead>(
         data.append(&mut v);
     }
 
+    log::debug!("read {} x {} trees", row_count, col_count);
     let arr = ndarray::Array2::from_shape_vec((row_count, col_count), data)?;
     Ok(arr)
 }
 
+/// Trees visible from outside the grid as `(col, row)`, the same as
+/// [`visible_any_side`] but for a matrix that's already been read.
+pub fn visible_trees(matrix: &ndarray::Array2<u8>) -> HashSet<(usize, usize)> {
+    let mut visible = HashSet::new();
+    for ((row, col), &height) in matrix.indexed_iter() {
+        let shorter = |n: &u8| *n < height;
+        if matrix.slice(s![..row, col]).iter().all(shorter)
+            || matrix.slice(s![row + 1.., col]).iter().all(shorter)
+            || matrix.slice(s![row, ..col]).iter().all(shorter)
+            || matrix.slice(s![row, col + 1..]).iter().all(shorter)
+        {
+            visible.insert((col, row));
+        }
+    }
+    log::debug!("{} trees visible", visible.len());
+    visible
+}
+
 pub fn visible_any_side<R: std::io::Read + std::io::Seek>(
     mut input: R,
 ) -> Result<HashSet<(usize, usize)>, MyError> {
@@ -270,11 +326,7 @@ mod tests {
 
     #[test]
     fn visible_any_side_works() {
-        let s = "30373
-25512
-65332
-33549
-35390";
+        let s = EXAMPLE;
         let mut r: HashSet<(usize, usize)> = HashSet::new();
         r.extend((0..5).map(|x| (x, 0)));
         r.extend((0..5).map(|x| (x, 4)));
@@ -292,13 +344,18 @@ mod tests {
         assert!(diff2.is_empty());
     }
 
+    #[test]
+    fn visible_trees_works() {
+        let mut reader = std::io::BufReader::new(EXAMPLE.as_bytes());
+        let matrix = read_into_matrix(&mut reader).unwrap();
+        let expected = visible_any_side(std::io::Cursor::new(EXAMPLE)).unwrap();
+        assert_eq!(expected, visible_trees(&matrix));
+        assert_eq!(21, visible_trees(&matrix).len());
+    }
+
     #[test]
     fn read_into_matrix_works() {
-        let s = "30373
-25512
-65332
-33549
-35390";
+        let s = EXAMPLE;
         let a: Vec<u8> = vec![
             3, 0, 3, 7, 3, 2, 5, 5, 1, 2, 6, 5, 3, 3, 2, 3, 3, 5, 4, 9, 3, 5, 3, 9, 0,
         ];
@@ -310,6 +367,15 @@ mod tests {
         assert_eq!(r, matrix);
     }
 
+    #[test]
+    fn read_into_matrix_rejects_no_trees() {
+        let mut reader = std::io::BufReader::new("x\n123\n".as_bytes());
+        assert!(matches!(
+            read_into_matrix(&mut reader),
+            Err(MyError::EmptyInput)
+        ));
+    }
+
     #[test]
     fn highest_score_works() {
         let a: Vec<u8> = vec![
@@ -317,7 +383,7 @@ mod tests {
         ];
         let matrix = ndarray::Array2::from_shape_vec((5, 5), a).unwrap();
 
-        let highest_score = highest_score(matrix);
+        let highest_score = highest_score(&matrix);
 
         assert_eq!(8, highest_score);
     }
@@ -0,0 +1,9 @@
+//! Day 9: Rope Bridge.
+
+mod mod_day9;
+
+pub use mod_day9::{
+    Day9, Direction, EXAMPLE, LARGER_EXAMPLE, Move, MyError, NUM_ROPE_KNOTS, NUM_ROPE_KNOTS_PART1,
+    RopeKnot, exec_move, exec_moves_list, exec_moves_list_with_knots, parse_move, parse_moves_list,
+    step_head, step_rope, step_tail,
+};
@@ -0,0 +1,346 @@
+use aoc_core::{Example, Solution};
+use std::collections::HashSet;
+use std::io::prelude::*;
+use thiserror::Error;
+
+pub const NUM_ROPE_KNOTS_PART1: usize = 2;
+pub const NUM_ROPE_KNOTS: usize = 10;
+
+pub const EXAMPLE: &str = include_str!("example.txt");
+pub const LARGER_EXAMPLE: &str = include_str!("larger_example.txt");
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Rope Bridge";
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(EXAMPLE, "13", "1"),
+        Example {
+            input: LARGER_EXAMPLE,
+            part1: None,
+            part2: Some("36"),
+        },
+    ];
+    type Parsed = Vec<Move>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = MyError;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        let mut reader = std::io::BufReader::new(input.as_bytes());
+        parse_moves_list(&mut reader)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Self::Answer1, Self::Error> {
+        let mut ml = parsed.clone();
+        Ok(exec_moves_list_with_knots::<NUM_ROPE_KNOTS_PART1>(&mut ml).len())
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Self::Answer2, Self::Error> {
+        let mut ml = parsed.clone();
+        Ok(exec_moves_list(&mut ml).len())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Move {
+    pub dir: Direction,
+    pub qty: usize,
+}
+
+impl Move {
+    pub fn dir_coords(&self) -> (isize, isize) {
+        match self.dir {
+            Direction::Up => (1, 0),
+            Direction::Down => (-1, 0),
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RopeKnot {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl RopeKnot {
+    pub fn get_coords(&self) -> (isize, isize) {
+        (self.row, self.col)
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum MyError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    ParseIntError(#[from] std::num::ParseIntError),
+    #[error("Couldn't parse direction of steps")]
+    ParseDir,
+    #[error("Couldn't parse quantity of steps")]
+    ParseQty,
+}
+
+pub fn exec_moves_list(ml: &mut [Move]) -> HashSet<(isize, isize)> {
+    exec_moves_list_with_knots::<NUM_ROPE_KNOTS>(ml)
+}
+
+pub fn exec_moves_list_with_knots<const N: usize>(ml: &mut [Move]) -> HashSet<(isize, isize)> {
+    let mut hs = HashSet::new();
+    let mut knots = [RopeKnot { row: 0, col: 0 }; N];
+    hs.insert(knots[knots.len() - 1].get_coords());
+
+    let visited = ml
+        .iter_mut()
+        .map(|m| exec_move(m, &mut knots))
+        .fold(hs, |mut acc, h| {
+            acc.extend(&h);
+            acc
+        });
+    log::debug!("tail of {} knots visited {} positions", N, visited.len());
+    visited
+}
+
+pub fn exec_move(m: &mut Move, knots: &mut [RopeKnot]) -> HashSet<(isize, isize)> {
+    let mut hs = HashSet::with_capacity((m.qty / (knots.len() - 1)) + 1);
+    while m.qty >= 1 {
+        hs.insert(step_rope(knots, m.dir_coords()));
+        m.qty -= 1;
+    }
+    hs
+}
+
+/// Moves the head one step and each knot after it to follow, returning
+/// where the tail ends up.
+pub fn step_rope(knots: &mut [RopeKnot], dir_coord: (isize, isize)) -> (isize, isize) {
+    let _ = step_head(&mut knots[0], dir_coord);
+    let num_middle_knots = (knots.len() as isize - 2) as usize;
+    if num_middle_knots >= 1 {
+        for i in 1..(num_middle_knots + 1) {
+            let head_coord = knots[i - 1].get_coords();
+            let _ = step_tail(&mut knots[i], head_coord);
+        }
+    }
+    let head_coord = knots[knots.len() - 2].get_coords();
+    step_tail(&mut knots[knots.len() - 1], head_coord)
+}
+
+pub fn step_head(head: &mut RopeKnot, dir_coord: (isize, isize)) -> (isize, isize) {
+    head.row += dir_coord.0;
+    head.col += dir_coord.1;
+    (head.row, head.col)
+}
+
+pub fn step_tail(tail: &mut RopeKnot, head_coord: (isize, isize)) -> (isize, isize) {
+    let row_diff = head_coord.0 - tail.row;
+    let col_diff = head_coord.1 - tail.col;
+
+    if (-1..=1).contains(&row_diff) && (-1..=1).contains(&col_diff) {
+        return (tail.row, tail.col);
+    };
+
+    let row_diff_dir = (head_coord.0 - tail.row).clamp(-1, 1);
+    let col_diff_dir = (head_coord.1 - tail.col).clamp(-1, 1);
+
+    tail.row += row_diff_dir;
+    tail.col += col_diff_dir;
+
+    (tail.row, tail.col)
+}
+
+pub fn parse_moves_list<R: std::io::BufRead>(reader: &mut R) -> Result<Vec<Move>, MyError> {
+    let mut v = Vec::new();
+    for line in reader.lines() {
+        let l = line?;
+        v.push(parse_move(l)?);
+    }
+    log::debug!("parsed {} moves", v.len());
+    Ok(v)
+}
+
+pub fn parse_move<S: Into<String>>(s: S) -> Result<Move, MyError> {
+    let string1: String = s.into();
+    let trim1 = string1.trim();
+    let mut iter = trim1.split_ascii_whitespace();
+
+    let maybe_dir = iter.next().ok_or(MyError::ParseDir)?;
+    let maybe_qty = iter.next().ok_or(MyError::ParseQty)?;
+
+    let dir = match maybe_dir {
+        "U" => Direction::Up,
+        "D" => Direction::Down,
+        "L" => Direction::Left,
+        "R" => Direction::Right,
+        _ => return Err(MyError::ParseDir),
+    };
+    let qty = maybe_qty.parse::<usize>()?;
+    Ok(Move { dir, qty })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_move_works() {
+        let s = EXAMPLE;
+        let v = s
+            .lines()
+            .map(parse_move)
+            .collect::<Result<Vec<_>, MyError>>()
+            .unwrap();
+        let r = vec![
+            Move {
+                dir: Direction::Right,
+                qty: 4,
+            },
+            Move {
+                dir: Direction::Up,
+                qty: 4,
+            },
+            Move {
+                dir: Direction::Left,
+                qty: 3,
+            },
+            Move {
+                dir: Direction::Down,
+                qty: 1,
+            },
+            Move {
+                dir: Direction::Right,
+                qty: 4,
+            },
+            Move {
+                dir: Direction::Down,
+                qty: 1,
+            },
+            Move {
+                dir: Direction::Left,
+                qty: 5,
+            },
+            Move {
+                dir: Direction::Right,
+                qty: 2,
+            },
+        ];
+
+        assert_eq!(r, v);
+    }
+
+    #[test]
+    fn parse_moves_list_works() {
+        let s = EXAMPLE;
+        let mut reader = std::io::BufReader::new(s.as_bytes());
+        let v = parse_moves_list(&mut reader).unwrap();
+        let r = vec![
+            Move {
+                dir: Direction::Right,
+                qty: 4,
+            },
+            Move {
+                dir: Direction::Up,
+                qty: 4,
+            },
+            Move {
+                dir: Direction::Left,
+                qty: 3,
+            },
+            Move {
+                dir: Direction::Down,
+                qty: 1,
+            },
+            Move {
+                dir: Direction::Right,
+                qty: 4,
+            },
+            Move {
+                dir: Direction::Down,
+                qty: 1,
+            },
+            Move {
+                dir: Direction::Left,
+                qty: 5,
+            },
+            Move {
+                dir: Direction::Right,
+                qty: 2,
+            },
+        ];
+
+        assert_eq!(r, v);
+    }
+
+    #[test]
+    fn exec_moves_list_works() {
+        let mut ml = vec![
+            Move {
+                dir: Direction::Right,
+                qty: 4,
+            },
+            Move {
+                dir: Direction::Up,
+                qty: 4,
+            },
+            Move {
+                dir: Direction::Left,
+                qty: 3,
+            },
+            Move {
+                dir: Direction::Down,
+                qty: 1,
+            },
+            Move {
+                dir: Direction::Right,
+                qty: 4,
+            },
+            Move {
+                dir: Direction::Down,
+                qty: 1,
+            },
+            Move {
+                dir: Direction::Left,
+                qty: 5,
+            },
+            Move {
+                dir: Direction::Right,
+                qty: 2,
+            },
+        ];
+        let hs_part1 = exec_moves_list_with_knots::<NUM_ROPE_KNOTS_PART1>(&mut ml.clone());
+        let hs = exec_moves_list(&mut ml);
+
+        // when NUM_ROPE_KNOTS = 2
+        assert_eq!(13, hs_part1.len());
+
+        // when NUM_ROPE_KNOTS = 10
+        assert_eq!(1, hs.len());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips() {
+        let moves = Day9::parse(EXAMPLE).unwrap();
+        let json = serde_json::to_string(&moves).unwrap();
+        assert!(json.starts_with(r#"[{"dir":"Right","qty":4},{"dir":"Up","qty":4}"#));
+        assert_eq!(moves, serde_json::from_str::<Vec<Move>>(&json).unwrap());
+
+        let knot = RopeKnot { row: -2, col: 3 };
+        let json = serde_json::to_string(&knot).unwrap();
+        assert_eq!(r#"{"row":-2,"col":3}"#, json);
+        assert_eq!(knot, serde_json::from_str(&json).unwrap());
+    }
+}